@@ -0,0 +1,25 @@
+// Compresses the word data built into the binary with the codec the features ask for, zstd
+// winning when both are on. Without either, res/words.toml is included as it is.
+fn main() {
+    println!("cargo:rerun-if-changed=res/words.toml");
+
+    let out = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let toml = std::fs::read("res/words.toml").unwrap();
+
+    #[cfg(feature = "zstd")]
+    std::fs::write(
+        out.join("words.toml.zst"),
+        zstd::encode_all(toml.as_slice(), 19).unwrap(),
+    )
+    .unwrap();
+
+    #[cfg(all(feature = "compressed", not(feature = "zstd")))]
+    {
+        let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::best());
+        std::io::Write::write_all(&mut encoder, &toml).unwrap();
+        std::fs::write(out.join("words.toml.bz2"), encoder.finish().unwrap()).unwrap();
+    }
+
+    #[cfg(not(any(feature = "compressed", feature = "zstd")))]
+    let _ = (out, toml);
+}
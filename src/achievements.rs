@@ -0,0 +1,202 @@
+/// What achievements are checked against after every test.
+pub struct Context<'a> {
+    pub history: &'a crate::history::History,
+    pub word_errors: &'a crate::stats::WordErrors,
+}
+
+pub struct Achievement {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    check: fn(&Context) -> bool,
+}
+
+fn best_wpm(context: &Context) -> f64 {
+    context
+        .history
+        .tests
+        .iter()
+        .filter(|entry| entry.settings.mode.is_timed_typing())
+        .map(|entry| entry.wpm)
+        .fold(0.0, f64::max)
+}
+
+pub const ACHIEVEMENTS: [Achievement; 9] = [
+    Achievement {
+        id: "first-test",
+        name: "open",
+        description: "finish a test",
+        check: |context| !context.history.tests.is_empty(),
+    },
+    Achievement {
+        id: "wpm-40",
+        name: "tawa",
+        description: "reach 40 wpm",
+        check: |context| best_wpm(context) >= 40.0,
+    },
+    Achievement {
+        id: "wpm-60",
+        name: "tawa wawa",
+        description: "reach 60 wpm",
+        check: |context| best_wpm(context) >= 60.0,
+    },
+    Achievement {
+        id: "wpm-80",
+        name: "tawa wawa mute",
+        description: "reach 80 wpm",
+        check: |context| best_wpm(context) >= 80.0,
+    },
+    Achievement {
+        id: "perfect",
+        name: "pona ale",
+        description: "type a test of at least 10 words with 100% accuracy",
+        check: |context| {
+            context.history.tests.iter().any(|entry| {
+                entry.settings.mode.is_timed_typing()
+                    && entry.words.1 >= 10
+                    && entry.accuracy >= 100.0
+            })
+        },
+    },
+    Achievement {
+        id: "daily",
+        name: "tenpo suno",
+        description: "finish a daily challenge",
+        check: |context| {
+            context
+                .history
+                .tests
+                .iter()
+                .any(|entry| entry.settings.mode == crate::words::Mode::Daily)
+        },
+    },
+    Achievement {
+        id: "streak-7",
+        name: "tenpo esun",
+        description: "practice 7 days in a row",
+        check: |context| {
+            let (_, longest) =
+                crate::progress::streaks(&crate::progress::practice_days(context.history));
+
+            longest >= 7
+        },
+    },
+    Achievement {
+        id: "tests-100",
+        name: "mute",
+        description: "finish 100 tests",
+        check: |context| context.history.tests.len() >= 100,
+    },
+    Achievement {
+        id: "core-mastered",
+        name: "sona",
+        description: "master every core word",
+        check: |context| {
+            crate::words::WORDS
+                .iter()
+                .filter(|word| word.usage_category == crate::words::UsageCategory::core)
+                .all(|word| context.word_errors.mastered(&word.id))
+        },
+    },
+];
+
+/// When each achievement was unlocked, in seconds since the unix epoch.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Unlocked {
+    pub unlocked: std::collections::BTreeMap<String, u64>,
+}
+
+pub static UNLOCKED: std::sync::LazyLock<std::sync::Mutex<Unlocked>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Unlocked::load()));
+
+impl Unlocked {
+    /// Loads the unlocked achievements from disk, starting with none.
+    pub fn load() -> Self {
+        crate::storage::load("achievements.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("achievements.toml", self);
+    }
+
+    /// Unlocks every achievement that is now met, returning the ones that are new.
+    pub fn evaluate(&mut self, context: &Context) -> Vec<&'static Achievement> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let new: Vec<&'static Achievement> = ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| !self.unlocked.contains_key(achievement.id))
+            .filter(|achievement| (achievement.check)(context))
+            .collect();
+
+        new.iter().for_each(|achievement| {
+            self.unlocked.insert(achievement.id.to_string(), now);
+        });
+
+        new
+    }
+}
+
+/// Lists every achievement and whether it has been unlocked.
+pub struct Trophies {
+    settings: crate::words::WordReq,
+}
+
+impl Trophies {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self { settings }
+    }
+}
+
+impl crate::flow::Store for Trophies {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        match action.char() {
+            _ if action.is(&crate::config::CONFIG.keys.quit) => {
+                Some(crate::flow::State::Settings {
+                    settings: self.settings.clone(),
+                })
+            }
+            Some('q') => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let unlocked = UNLOCKED.lock().unwrap();
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line(
+            format!(
+                "{}/{} unlocked",
+                unlocked.unlocked.len(),
+                ACHIEVEMENTS.len()
+            )
+            .bold(),
+        );
+        text.push_line(ratatui::text::Line::default());
+        ACHIEVEMENTS.iter().for_each(|achievement| {
+            let line = format!("{}: {}", achievement.name, achievement.description);
+            match unlocked.unlocked.contains_key(achievement.id) {
+                true => text.push_line(format!("✓ {line}").yellow()),
+                false => text.push_line(format!("  {line}").dark_gray()),
+            }
+        });
+        text.push_line(ratatui::text::Line::default());
+        text.push_line(ratatui::text::Line::from("q: back").dark_gray());
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(text),
+            block.inner(frame.area()),
+        );
+    }
+}
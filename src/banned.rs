@@ -0,0 +1,31 @@
+/// Words marked to never be shown, from the dictionary or the details of a word in a test.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Banned {
+    pub words: std::collections::BTreeSet<String>,
+}
+
+pub static BANNED: std::sync::LazyLock<std::sync::Mutex<Banned>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Banned::load()));
+
+impl Banned {
+    pub fn load() -> Self {
+        crate::storage::load("banned.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("banned.toml", self);
+    }
+
+    /// Whether the word is never shown, by being marked or by being in the config's list.
+    pub fn contains(&self, id: &str) -> bool {
+        self.words.contains(id) || crate::config::CONFIG.banned.iter().any(|word| word == id)
+    }
+
+    /// Marks the word as never shown, or shows it again if it was marked. Words banned in the config
+    /// stay banned.
+    pub fn toggle(&mut self, id: &str) {
+        if !self.words.remove(id) {
+            self.words.insert(id.to_string());
+        }
+    }
+}
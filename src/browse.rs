@@ -0,0 +1,171 @@
+/// Dictionary page with a fuzzy search over word ids and definitions.
+pub struct Browse {
+    settings: crate::words::WordReq,
+    query: String,
+    matches: Vec<&'static crate::words::WordData>,
+    selected: usize,
+    /// The first word shown when last drawn, which clicks are counted from.
+    offset: std::cell::Cell<usize>,
+}
+
+impl Browse {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let mut browse = Self {
+            settings,
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            offset: std::cell::Cell::new(0),
+        };
+        browse.search();
+
+        browse
+    }
+
+    /// Ranks every word against the query, favouring matches on the word over its definition.
+    fn search(&mut self) {
+        let lang = &self.settings.lang;
+
+        let mut scored: Vec<(i32, &'static crate::words::WordData)> = crate::words::all()
+            .into_iter()
+            .filter_map(|word| {
+                let id = crate::dict::fuzzy(&self.query, &word.id).map(|score| score * 4);
+                let definition = word
+                    .definition(lang)
+                    .and_then(|definition| crate::dict::fuzzy(&self.query, definition));
+
+                id.max(definition).map(|score| (score, word))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.id.cmp(&b.1.id)));
+
+        self.matches = scored.into_iter().map(|(_, word)| word).collect();
+        self.selected = 0;
+        self.offset.set(0);
+    }
+}
+
+impl crate::flow::Store for Browse {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = self.matches.len().max(1);
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+        // The list starts below the padding and search line, 16 columns wide.
+        if let Some(click) = action.click().filter(|click| (1..17).contains(&click.x)) {
+            let row = (click.y as usize)
+                .checked_sub(3)
+                .map(|row| row + self.offset.get());
+            if let Some(row) = row.filter(|row| *row < self.matches.len()) {
+                self.selected = row;
+            }
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&crate::config::CONFIG.keys.ban) {
+            if let Some(word) = self.matches.get(self.selected) {
+                crate::banned::BANNED.lock().unwrap().toggle(&word.id);
+            }
+
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Up => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down => self.selected = (self.selected + 1) % rows,
+            // Only words made in the editor can be changed in it.
+            KeyCode::Enter => {
+                let personal = crate::words::personal_pack()
+                    .and_then(|path| Some(path.file_stem()?.to_string_lossy().into_owned()));
+                if let Some(word) = self
+                    .matches
+                    .get(self.selected)
+                    .filter(|word| word.pack.is_some() && word.pack == personal)
+                {
+                    return Some(crate::flow::State::Editor {
+                        settings: self.settings.clone(),
+                        word: Some(word.id.clone()),
+                    });
+                }
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.search();
+            }
+            _ => {
+                if let Some(c) = action.char() {
+                    self.query.push(c);
+                    self.search();
+                }
+            }
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [search, body, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(2),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+        let [list, detail] = ratatui::layout::Layout::horizontal([
+            ratatui::layout::Constraint::Length(16),
+            ratatui::layout::Constraint::Min(0),
+        ])
+        .spacing(2)
+        .areas(body);
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(format!("search: {}_", self.query)),
+            search,
+        );
+
+        let items = self
+            .matches
+            .iter()
+            .map(|word| ratatui::widgets::ListItem::new(word.word.as_str()));
+        let mut state = ratatui::widgets::ListState::default()
+            .with_offset(self.offset.get())
+            .with_selected(Some(self.selected));
+        frame.render_stateful_widget(
+            ratatui::widgets::List::new(items)
+                .highlight_style(ratatui::style::Style::new().reversed()),
+            list,
+            &mut state,
+        );
+        self.offset.set(state.offset());
+
+        if let Some(word) = self.matches.get(self.selected) {
+            crate::dict::render(frame, detail, word, &self.settings.lang);
+        }
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                format!(
+                    "type to search   up/down: select   enter: change a word of yours   {}: ban or unban   {}: back",
+                    crate::keys::describe(&crate::config::CONFIG.keys.ban),
+                    crate::keys::describe(&crate::config::CONFIG.keys.quit)
+                )
+                .dark_gray(),
+            ),
+            help,
+        );
+    }
+}
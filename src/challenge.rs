@@ -0,0 +1,77 @@
+/// A test everyone on a server types: the settings, seed included, and the words they give.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct Challenge {
+    pub settings: crate::words::WordReq,
+    /// Ids of the words in the test, which are what is typed so the test doesn't depend on the
+    /// word data and stats of whoever takes it.
+    pub words: Vec<String>,
+}
+
+/// A result sent in for a challenge.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct Submission {
+    pub name: String,
+    pub seed: u64,
+    pub wpm: f64,
+    pub accuracy: f64,
+    /// Length of the test in seconds.
+    pub elapsed: f64,
+}
+
+/// What the server answers a submission with.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Placement {
+    pub rank: usize,
+    pub of: usize,
+}
+
+/// The settings of the current challenge of the server in the config, with its words. Only how
+/// the test is shown is kept from the given settings.
+#[cfg(feature = "online")]
+pub fn take(base: &crate::words::WordReq) -> Result<crate::words::WordReq, String> {
+    let url = crate::config::CONFIG
+        .server
+        .url
+        .as_ref()
+        .ok_or("no server set, add one under [server] in the config")?;
+    let challenge = fetch(url)?;
+
+    Ok(crate::words::WordReq {
+        lang: base.lang.clone(),
+        sitelen_pona: base.sitelen_pona,
+        keyboard: base.keyboard,
+        ghost: base.ghost,
+        hint: base.hint,
+        hint_delay: base.hint_delay,
+        fixed: challenge.words,
+        ..challenge.settings
+    })
+}
+
+/// Fetches the current challenge from a server.
+#[cfg(feature = "online")]
+pub fn fetch(server: &str) -> Result<Challenge, String> {
+    let response = ureq::get(&format!("{}/challenge", server.trim_end_matches('/')))
+        .call()
+        .map_err(|err| format!("could not reach {server}: {err}"))?;
+
+    serde_json::from_reader(response.into_reader())
+        .map_err(|err| format!("could not read the challenge from {server}: {err}"))
+}
+
+/// Sends a result to a server, returning where it placed.
+#[cfg(feature = "online")]
+pub fn submit(server: &str, submission: &Submission) -> Result<Placement, String> {
+    let response = ureq::post(&format!("{}/results", server.trim_end_matches('/')))
+        .set("Content-Type", "application/json")
+        .send_string(&serde_json::to_string(submission).unwrap())
+        .map_err(|err| match err {
+            ureq::Error::Status(_, response) => {
+                response.into_string().unwrap_or_else(|err| err.to_string())
+            }
+            err => format!("could not reach {server}: {err}"),
+        })?;
+
+    serde_json::from_reader(response.into_reader())
+        .map_err(|err| format!("could not read the answer from {server}: {err}"))
+}
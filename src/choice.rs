@@ -0,0 +1,208 @@
+/// A word along with the shuffled candidates offered as its definition.
+struct Card {
+    word: &'static crate::words::WordData,
+    options: Vec<&'static crate::words::WordData>,
+}
+
+impl Card {
+    /// Picks three other words of the same usage category as wrong answers.
+    fn new(word: &'static crate::words::WordData, rng: &mut impl rand::Rng) -> Self {
+        use rand::seq::{IteratorRandom, SliceRandom};
+
+        let mut options = crate::words::WORDS
+            .iter()
+            .filter(|other| other.usage_category == word.usage_category)
+            .filter(|other| other.id != word.id && other.definitions.is_some())
+            .filter(|other| !crate::banned::BANNED.lock().unwrap().contains(&other.id))
+            .choose_multiple(rng, 3);
+        options.push(word);
+        options.shuffle(rng);
+
+        Self { word, options }
+    }
+}
+
+/// Shows a word and asks which of four definitions belongs to it.
+pub struct Choice {
+    settings: crate::words::WordReq,
+    cards: Vec<Card>,
+    /// Index of the option picked for every answered card.
+    answers: Vec<usize>,
+    selected: usize,
+    start: Option<std::time::Instant>,
+    keystrokes: usize,
+    seed: u64,
+}
+
+impl Choice {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let seed = crate::words::seed(&settings);
+        let mut rng = crate::words::seeded_rng(seed);
+
+        Self {
+            cards: crate::words::subset(&settings, &mut rng)
+                .into_iter()
+                .filter(|word| word.definitions.is_some())
+                .map(|word| Card::new(word, &mut rng))
+                .collect(),
+            settings,
+            answers: Vec::new(),
+            selected: 0,
+            start: None,
+            keystrokes: 0,
+            seed,
+        }
+    }
+
+    fn answer(&mut self, option: usize) {
+        let card = &self.cards[self.answers.len()];
+        let Some(picked) = card.options.get(option) else {
+            return;
+        };
+        let correct = picked.id == card.word.id;
+
+        crate::stats::WORD_ERRORS
+            .lock()
+            .unwrap()
+            .record(&card.word.id, correct);
+        crate::srs::SCHEDULE
+            .lock()
+            .unwrap()
+            .review(&card.word.id, if correct { 4 } else { 1 });
+
+        self.answers.push(option);
+        self.selected = 0;
+    }
+
+    fn is_correct(&self, index: usize) -> bool {
+        let card = &self.cards[index];
+
+        card.options[self.answers[index]].id == card.word.id
+    }
+
+    fn finish(&self) -> crate::flow::State {
+        let right = (0..self.answers.len())
+            .filter(|index| self.is_correct(*index))
+            .count();
+        let elapsed = self.start.map(crate::clock::elapsed).unwrap_or_default();
+
+        let mut result = crate::results::TestResult::answers(
+            right,
+            self.answers.len(),
+            self.keystrokes,
+            elapsed,
+        );
+        result.missed = (0..self.answers.len())
+            .filter(|index| !self.is_correct(*index))
+            .map(|index| self.cards[index].word.id.clone())
+            .collect();
+        result.seed = Some(self.seed);
+
+        crate::flow::State::Results {
+            settings: self.settings.clone(),
+            result: Box::new(result),
+        }
+    }
+}
+
+impl crate::flow::Store for Choice {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        let Some(card) = self.cards.get(self.answers.len()) else {
+            return Some(self.finish());
+        };
+
+        let keys = &crate::config::CONFIG.keys;
+        if action.is(&keys.quit) {
+            return Some(self.finish());
+        }
+        if action.is(&keys.restart) {
+            return Some(crate::flow::State::Game {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&keys.settings) {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        let options = card.options.len();
+
+        self.start.get_or_insert_with(crate::clock::now);
+        self.keystrokes += 1;
+
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') => self.answer(c as usize - '1' as usize),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + options - 1) % options
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % options,
+            KeyCode::Enter | KeyCode::Char(' ') => self.answer(self.selected),
+            _ => (),
+        }
+
+        match self.answers.len() >= self.cards.len() {
+            true => Some(self.finish()),
+            false => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let mut text = ratatui::text::Text::default();
+
+        text.push_line(
+            ratatui::text::Line::from(format!("{}/{}", self.answers.len(), self.cards.len()))
+                .dark_gray(),
+        );
+        text.push_line(ratatui::text::Line::default());
+
+        if let Some(card) = self.cards.get(self.answers.len()) {
+            text.push_line(ratatui::text::Line::from(card.word.word.as_str()).bold());
+            text.push_line(ratatui::text::Line::default());
+
+            card.options.iter().enumerate().for_each(|(index, option)| {
+                let line = ratatui::text::Line::from(format!(
+                    "{}. {}",
+                    index + 1,
+                    option.definition(&self.settings.lang).unwrap_or_default()
+                ));
+
+                text.push_line(match index == self.selected {
+                    true => line.reversed(),
+                    false => line,
+                });
+            });
+            text.push_line(ratatui::text::Line::default());
+        }
+
+        if let Some(index) = self.answers.len().checked_sub(1) {
+            let word = self.cards[index].word;
+
+            text.push_line(match self.is_correct(index) {
+                true => ratatui::text::Line::from(format!("{} ✓", word.word)).green(),
+                false => ratatui::text::Line::from(format!(
+                    "{} ✗ {}",
+                    word.word,
+                    word.definition(&self.settings.lang).unwrap_or_default()
+                ))
+                .red(),
+            });
+        }
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(text).wrap(ratatui::widgets::Wrap { trim: false }),
+            block.inner(frame.area()),
+        );
+    }
+}
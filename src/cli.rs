@@ -0,0 +1,475 @@
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Requirement {
+    Ku,
+    Pu,
+    Commentary,
+    Definitions,
+}
+
+#[derive(clap::Subcommand)]
+pub enum Command {
+    /// Download the latest word data from Linku and use it instead of the built-in words
+    #[cfg(feature = "update")]
+    Update,
+    /// Host a challenge others can take with --challenge, built from the test options given
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address and port to listen on
+        #[arg(long, default_value = "0.0.0.0:7878")]
+        address: String,
+    },
+    /// Look a word up in the dictionary
+    Dict {
+        /// The word to look up
+        word: String,
+
+        /// Print the full word data as json
+        #[arg(long)]
+        json: bool,
+
+        /// Language to show definitions in
+        #[arg(short, long, default_value = "en")]
+        lang: String,
+    },
+    /// Write the test history and per-word stats to a file
+    Export {
+        #[arg(long, value_enum)]
+        format: crate::export::Format,
+
+        /// File to write to; csv also writes the word stats next to it as <name>-words.csv
+        #[arg(long)]
+        out: std::path::PathBuf,
+    },
+    /// Add the results exported from another typing app to the history
+    Import {
+        #[arg(value_enum)]
+        source: crate::import::Source,
+
+        /// The exported results
+        file: std::path::PathBuf,
+    },
+    /// Carry the history and word stats between machines
+    Sync {
+        #[command(subcommand)]
+        action: Sync,
+    },
+    /// Check a word list for --wordlist, listing every problem with it and where it is
+    Validate {
+        /// The word list, laid out like the built-in words
+        file: std::path::PathBuf,
+    },
+    /// Show the word of the day, the same for everyone, and drill it
+    Wotd,
+    /// Play a test from the options given by a script of keys and pauses, without a terminal or
+    /// any saved data, and print the screen it ends on
+    Simulate {
+        /// Steps of the script, one per line: "pace <ms>", "type <text>", "key <key>" or
+        /// "wait <ms>"
+        script: std::path::PathBuf,
+
+        #[arg(long, default_value_t = 100)]
+        width: u16,
+
+        #[arg(long, default_value_t = 30)]
+        height: u16,
+    },
+    /// Write the words you miss often or type slowly as cards Anki can import
+    Anki {
+        /// File to write the tab separated cards to
+        #[arg(long)]
+        out: std::path::PathBuf,
+
+        /// Include words missed more often than this share of the time
+        #[arg(long, default_value_t = 0.2)]
+        error_rate: f64,
+
+        /// Include words typed slower than this many seconds per character
+        #[arg(long, default_value_t = 0.5)]
+        seconds_per_char: f64,
+
+        /// Language of the definitions on the back of the cards
+        #[arg(short, long, default_value = "en")]
+        lang: String,
+    },
+}
+
+#[derive(clap::Subcommand)]
+pub enum Sync {
+    /// Write the history and word stats to a file to import on another machine
+    Export {
+        /// File to write the bundle to
+        file: std::path::PathBuf,
+    },
+    /// Merge a bundle exported on another machine into this one's history and word stats
+    Import {
+        /// The bundle to merge
+        file: std::path::PathBuf,
+    },
+}
+
+/// a toki pona typing game
+#[derive(clap::Parser)]
+#[command(version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Number of words in the test
+    #[arg(short, long)]
+    pub words: Option<usize>,
+
+    /// Make the test last this many seconds instead of a fixed number of words
+    #[arg(short, long)]
+    pub time: Option<u64>,
+
+    /// Type freely without any target text
+    #[arg(long)]
+    pub zen: bool,
+
+    /// Type each word from its definition alone
+    #[arg(long, conflicts_with = "zen")]
+    pub quiz: bool,
+
+    /// Whether --quiz asks for words from their definitions or for the meanings of words
+    #[arg(long, value_enum)]
+    pub direction: Option<crate::words::Direction>,
+
+    /// Pick the right definition for each word out of four
+    #[arg(long, conflicts_with_all = ["zen", "quiz"])]
+    pub choice: bool,
+
+    /// Take today's daily challenge, which has the same words for everyone
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice"])]
+    pub daily: bool,
+
+    /// Take the current challenge of the server set in the config
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily"])]
+    pub challenge: bool,
+
+    /// Type each word from hearing it said, which needs word data from `sona update`
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily"])]
+    pub dictation: bool,
+
+    /// Type set phrases like "tomo tawa", each counted as one target
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation"])]
+    pub phrases: bool,
+
+    /// Type made up sentences instead of unrelated words
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases"])]
+    pub sentences: bool,
+
+    /// Type an excerpt of a toki pona text, from the bundled ones or the quotes directory
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases", "sentences"])]
+    pub quotes: bool,
+
+    /// Type sentences with numbers, names and punctuation
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases", "sentences", "quotes"])]
+    pub numbers: bool,
+
+    /// Type the words unlocked so far in the course, which adds words as they are learned
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases", "sentences", "quotes", "numbers"])]
+    pub learn: bool,
+
+    /// Drill these letters with words that are full of them, like "jwn"
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases", "sentences", "quotes", "numbers", "learn"])]
+    pub drill: Option<String>,
+
+    /// Show each word and type its first meaning instead
+    #[arg(long, conflicts_with_all = ["zen", "quiz", "choice", "daily", "dictation", "phrases", "sentences", "quotes", "numbers", "learn", "drill"])]
+    pub definitions: bool,
+
+    /// How involved the sentences of --sentences are
+    #[arg(long, value_enum)]
+    pub complexity: Option<crate::sentence::Complexity>,
+
+    /// Usage categories to pick words from
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    pub categories: Option<Vec<crate::words::UsageCategory>>,
+
+    /// Data every picked word must have
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    pub require: Option<Vec<Requirement>>,
+
+    /// Include deprecated words
+    #[arg(long, overrides_with = "no_deprecated")]
+    pub deprecated: bool,
+
+    /// Leave out deprecated words
+    #[arg(long, overrides_with = "deprecated")]
+    pub no_deprecated: bool,
+
+    /// Favour words you often miss or type slowly
+    #[arg(long)]
+    pub adaptive: bool,
+
+    /// Only use words that are due for review
+    #[arg(long)]
+    pub review: bool,
+
+    /// Draw words again when the test is longer than the words available
+    #[arg(long)]
+    pub repeat: bool,
+
+    /// Only use words with at least this many letters
+    #[arg(long)]
+    pub min_length: Option<usize>,
+
+    /// Only use words with at most this many letters
+    #[arg(long)]
+    pub max_length: Option<usize>,
+
+    /// Only use words with at least one of these letters, like "asjkl"
+    #[arg(long)]
+    pub with_letters: Option<String>,
+
+    /// Leave out words with any of these letters
+    #[arg(long)]
+    pub without_letters: Option<String>,
+
+    /// Language to show definitions in, such as "de" or "eo"
+    #[arg(short, long)]
+    pub lang: Option<String>,
+
+    /// Word pack to practice, laid out like the built-in words.toml in TOML, JSON or YAML by its
+    /// extension. Can be given more than once, later packs overriding words with the same id in
+    /// earlier ones and in the packs from the config
+    #[arg(long)]
+    pub wordlist: Vec<std::path::PathBuf>,
+
+    /// Use only the words from the word packs instead of adding them to the built-in words
+    #[arg(long)]
+    pub replace_words: bool,
+
+    /// Show words as sitelen pona, which needs a font with UCSUR glyphs
+    #[arg(long)]
+    pub sitelen_pona: bool,
+
+    /// Show a keyboard under the text that marks the keys being pressed
+    #[arg(long)]
+    pub keyboard: bool,
+
+    /// Turn off backspace and deleting words, so what is typed is scored exactly
+    #[arg(long)]
+    pub hardcore: bool,
+
+    /// Keep mistakes hidden until the results
+    #[arg(long)]
+    pub blind: bool,
+
+    /// Show only the text and what it is typed from
+    #[arg(long)]
+    pub minimal: bool,
+
+    /// When to show the definition of the word being typed
+    #[arg(long, value_enum)]
+    pub hint: Option<crate::words::Hint>,
+
+    /// Seconds without typing before a delayed hint is shown
+    #[arg(long)]
+    pub hint_delay: Option<u64>,
+
+    /// Whether wrong keys are typed anyway or refused until the right one is pressed
+    #[arg(long, value_enum)]
+    pub strictness: Option<crate::words::Strictness>,
+
+    /// What space does in the middle of a word
+    #[arg(long, value_enum)]
+    pub space: Option<crate::words::Space>,
+
+    /// Print the slowest and most missed words with how they changed since last week, and exit
+    #[arg(long)]
+    pub report: bool,
+
+    /// Seed for picking words, so the same seed gives the same test; any text works
+    #[arg(long, value_parser = crate::words::parse_seed)]
+    pub seed: Option<u64>,
+}
+
+impl Cli {
+    /// Whether any test option was passed, in which case the settings screen is skipped.
+    pub fn configures_test(&self) -> bool {
+        self.words.is_some()
+            || self.challenge
+            || self.lang.is_some()
+            || self.time.is_some()
+            || self.zen
+            || self.quiz
+            || self.choice
+            || self.daily
+            || self.dictation
+            || self.phrases
+            || self.sentences
+            || self.quotes
+            || self.numbers
+            || self.learn
+            || self.drill.is_some()
+            || self.definitions
+            || self.complexity.is_some()
+            || self.categories.is_some()
+            || self.require.is_some()
+            || self.deprecated
+            || self.no_deprecated
+            || self.adaptive
+            || self.review
+            || self.repeat
+            || self.min_length.is_some()
+            || self.max_length.is_some()
+            || self.with_letters.is_some()
+            || self.without_letters.is_some()
+            || self.sitelen_pona
+            || self.keyboard
+            || self.hardcore
+            || self.blind
+            || self.minimal
+            || self.hint.is_some()
+            || self.hint_delay.is_some()
+            || self.strictness.is_some()
+            || self.space.is_some()
+            || self.direction.is_some()
+            || self.seed.is_some()
+    }
+
+    /// Overrides the given settings with the options that were passed.
+    pub fn apply(&self, settings: &mut crate::words::WordReq) {
+        use crate::words::UsageCategory;
+
+        if let Some(lang) = &self.lang {
+            settings.lang = lang.clone();
+        }
+
+        if let Some(n) = self.words {
+            settings.mode = crate::words::Mode::Words;
+            settings.n = n;
+        }
+
+        if let Some(time) = self.time {
+            settings.mode = crate::words::Mode::Time;
+            settings.time = time;
+        }
+
+        if self.zen {
+            settings.mode = crate::words::Mode::Zen;
+        }
+
+        if self.quiz {
+            settings.mode = crate::words::Mode::Quiz;
+        }
+
+        if self.choice {
+            settings.mode = crate::words::Mode::Choice;
+        }
+
+        if self.daily {
+            settings.mode = crate::words::Mode::Daily;
+        }
+
+        if self.dictation {
+            settings.mode = crate::words::Mode::Dictation;
+        }
+
+        if self.phrases {
+            settings.mode = crate::words::Mode::Phrases;
+        }
+
+        if self.sentences {
+            settings.mode = crate::words::Mode::Sentences;
+        }
+
+        if self.quotes {
+            settings.mode = crate::words::Mode::Quotes;
+        }
+
+        if self.numbers {
+            settings.mode = crate::words::Mode::Numbers;
+        }
+
+        if self.learn {
+            settings.mode = crate::words::Mode::Learn;
+        }
+
+        if let Some(letters) = &self.drill {
+            settings.mode = crate::words::Mode::Drill;
+            settings.letters = letters.to_lowercase();
+        }
+
+        if self.definitions {
+            settings.mode = crate::words::Mode::Definitions;
+        }
+
+        if let Some(complexity) = self.complexity {
+            settings.complexity = complexity;
+        }
+
+        if let Some(categories) = &self.categories {
+            settings.core = categories.contains(&UsageCategory::core);
+            settings.common = categories.contains(&UsageCategory::common);
+            settings.uncommon = categories.contains(&UsageCategory::uncommon);
+            settings.obscure = categories.contains(&UsageCategory::obscure);
+            settings.sandbox = categories.contains(&UsageCategory::sandbox);
+        }
+
+        if let Some(require) = &self.require {
+            settings.ku = false;
+            settings.pu = false;
+            settings.commentary = false;
+            settings.definitions = false;
+
+            require.iter().for_each(|requirement| match requirement {
+                Requirement::Ku => settings.ku = true,
+                Requirement::Pu => settings.pu = true,
+                Requirement::Commentary => settings.commentary = true,
+                Requirement::Definitions => settings.definitions = true,
+            });
+        }
+
+        if self.deprecated {
+            settings.deprecated = true;
+        }
+        if self.no_deprecated {
+            settings.deprecated = false;
+        }
+
+        if let Some(length) = self.min_length {
+            settings.min_length = length;
+        }
+        if let Some(length) = self.max_length {
+            settings.max_length = length;
+        }
+        if let Some(letters) = &self.with_letters {
+            settings.with_letters = letters.to_lowercase();
+        }
+        if let Some(letters) = &self.without_letters {
+            settings.without_letters = letters.to_lowercase();
+        }
+
+        settings.adaptive |= self.adaptive;
+        settings.review |= self.review;
+        settings.repeat |= self.repeat;
+        settings.sitelen_pona |= self.sitelen_pona;
+        settings.keyboard |= self.keyboard;
+        settings.hardcore |= self.hardcore;
+        settings.blind |= self.blind;
+        settings.minimal |= self.minimal;
+
+        if let Some(hint) = self.hint {
+            settings.hint = hint;
+        }
+        if let Some(delay) = self.hint_delay {
+            settings.hint = crate::words::Hint::Delayed;
+            settings.hint_delay = delay;
+        }
+        if let Some(strictness) = self.strictness {
+            settings.strictness = strictness;
+        }
+        if let Some(space) = self.space {
+            settings.space = space;
+        }
+        if let Some(direction) = self.direction {
+            settings.direction = direction;
+        }
+
+        if self.seed.is_some() {
+            settings.seed = self.seed;
+        }
+    }
+}
@@ -0,0 +1,24 @@
+thread_local! {
+    /// Set while a simulation runs, which moves the time by hand instead of letting it pass.
+    static SIMULATED: std::cell::Cell<Option<std::time::Instant>> = const { std::cell::Cell::new(None) };
+}
+
+/// The current time, or the simulated time once [`simulate`] has been called on this thread.
+pub fn now() -> std::time::Instant {
+    SIMULATED.get().unwrap_or_else(std::time::Instant::now)
+}
+
+/// Time since `instant`, like [`std::time::Instant::elapsed`] but by [`now`].
+pub fn elapsed(instant: std::time::Instant) -> std::time::Duration {
+    now().saturating_duration_since(instant)
+}
+
+/// Stops the clock on this thread, so it only moves when [`advance`] is called.
+pub fn simulate() {
+    SIMULATED.set(Some(now()));
+}
+
+/// Moves the simulated clock forward.
+pub fn advance(duration: std::time::Duration) {
+    SIMULATED.set(SIMULATED.get().map(|instant| instant + duration));
+}
@@ -0,0 +1,424 @@
+/// Written to the config directory the first time sona runs.
+const DEFAULT_CONFIG: &str = r##"# sona configuration
+# Command line options override anything set here.
+
+# How the typing position is marked: "block", "underline" or "blink".
+caret = "block"
+
+# Color scheme used for the typing text.
+# Built in are "default", "light", "dark", "high-contrast", "deuteranopia",
+# "protanopia" and "gruvbox".
+theme = "default"
+
+# How mistakes stand out: "color" alone, or "symbols" which also strikes
+# through wrong letters and puts extra letters in italics.
+marks = "color"
+
+# Lets the mouse select options and list items, and scroll through them.
+mouse = true
+
+# Seconds without a key before a test pauses itself, leaving the time away out
+# of the speed and the time of the word being typed. 0 never pauses.
+idle = 10
+
+# Where the history and word stats are kept: "files" for TOML files, or
+# "sqlite" for a database that also keeps every keystroke of every test, which
+# needs sona built with the "sqlite" feature. The first run with a new
+# database starts from what the files had.
+storage = "files"
+
+# Show the word of the day the first time sona opens each day. It can always
+# be seen with `sona wotd`.
+word_of_the_day = true
+
+# Words never shown in any test, by id, besides those marked from the
+# dictionary or the details of a word during a test.
+# banned = ["kin", "namako"]
+
+# A directory of .txt files with toki pona texts for quote mode, besides the
+# bundled ones. A last line starting with "--" names where a text is from.
+# quotes = "/home/jan/lipu"
+
+# Word packs laid over the built-in words, in the layout of the built-in
+# words.toml. Later packs override words with the same id in earlier ones, and
+# packs given with --wordlist go on top of these. Words made in sona's own
+# editor are kept in nimi-sin.toml in the data directory, under all of these.
+# packs = ["/home/jan/nimi-sin.toml", "/home/jan/tomo-sona.yaml"]
+
+# Lessons besides the bundled ones are read from .toml files in a "lessons"
+# folder next to this file, each a list of lessons like:
+#   [[lessons]]
+#   name = "colors"
+#   words = ["kule", "loje", "jelo", "laso", "walo", "pimeja"]
+#   wpm = 25
+#   accuracy = 95
+#   rounds = 3
+
+# Which words a test is built from.
+[words]
+# Language definitions are shown in, when the word data has it.
+lang = "en"
+# How a test goes:
+# "words": ends after n words.
+# "time": ends after the given number of seconds.
+# "zen": has no target text at all.
+# "quiz": shows only the definitions, or only the words with "recognize".
+# "choice": asks for the right definition out of four.
+# "daily": the same words for everyone each day.
+# "dictation": says each word out loud, with the word data from `sona update`.
+# "phrases": n set phrases like "tomo tawa".
+# "sentences": made up sentences of about n words.
+# "quotes": about n words of a toki pona text.
+# "numbers": sentences with numbers in digits, typed in words.
+# "learn": a course that adds words as the ones so far are learned.
+# "drill": n words full of the letters set in letters.
+# "definitions": shows each word and has its first meaning typed.
+mode = "words"
+time = 30
+in_use = true
+deprecated = false
+core = true
+common = false
+uncommon = false
+obscure = false
+sandbox = false
+# Only pick words that have this data.
+ku = false
+pu = false
+commentary = false
+definitions = true
+# Favour words you often miss or type slowly.
+adaptive = false
+# Only use words that are due for review.
+review = false
+# Show words as sitelen pona, which needs a font with UCSUR glyphs.
+sitelen_pona = false
+# Show a keyboard under the text that marks the keys being pressed.
+keyboard = false
+# Number of words in a test.
+n = 50
+# Draw words again when n is more than the words available, instead of
+# cutting the test short.
+repeat = false
+# Race the personal best with the same settings, shown as a second progress bar.
+ghost = true
+# Turn off backspace and deleting words, so what is typed is scored exactly.
+hardcore = false
+# Draw everything typed in one color and leave accuracy out of the status line,
+# keeping mistakes hidden until the results.
+blind = false
+# Show only the text and what it is typed from, without the status line,
+# progress or keyboard. Terminals smaller than 60x16 get a compact layout
+# with all of that on one line either way.
+minimal = false
+# When the definition of the word being typed is shown: "always", "delayed"
+# once nothing has been typed for hint_delay seconds, "on-demand" when the
+# hint key is pressed, or "off".
+hint = "always"
+hint_delay = 3
+# What a wrong key does: "forgiving" types it anyway, so it can be fixed or
+# left, and "stop" refuses it, so the caret waits for the right key. Refused
+# keys still count against accuracy.
+strictness = "forgiving"
+# What space does before the word is complete: "skip" moves on to the next
+# word and leaves the rest of this one wrong, "insert" types it into the word
+# as a wrong character and "ignore" does nothing.
+space = "skip"
+# Which way round quiz mode asks: "recall" shows a definition and asks for the
+# word, "recognize" shows the word and asks for any one of its meanings.
+direction = "recall"
+# How involved the sentences of sentence mode are: "simple", "compound" with
+# objects and adjectives, or "complex" with prepositions and "la" too.
+complexity = "simple"
+# Letters drill mode picks words full of.
+letters = "jwn"
+# Only words with at least min_length and at most max_length letters, where 0
+# leaves either end open.
+min_length = 0
+max_length = 0
+# Only words with at least one of with_letters and none of without_letters,
+# like "asjkl" for the home row of a QWERTY keyboard. Empty for any word.
+with_letters = ""
+without_letters = ""
+
+# Where the text being typed goes on wide terminals.
+[layout]
+# Lines are never wider than this many columns, so they stay easy to read.
+# 0 lets them fill the terminal.
+max_width = 80
+# Put the text in the middle of the terminal instead of on the left.
+center = true
+
+# Keys are written like "q", "esc", "tab", "f1" or "ctrl+c", either alone or
+# as a list of alternatives.
+[keys]
+# Ends the current test, or leaves sona from the other pages.
+quit = ["esc", "ctrl+c"]
+# Starts a new test with the same settings.
+restart = "tab"
+# Goes back to the settings page.
+settings = "ctrl+s"
+# Stops the clock and hides the test until any key is pressed.
+pause = "ctrl+p"
+# Shows the dictionary entry of the word being typed, pausing the test meanwhile.
+details = "ctrl+d"
+# Deletes the word being typed. Most terminals send ctrl+backspace as ctrl+h.
+delete_word = ["ctrl+backspace", "ctrl+h", "ctrl+w", "alt+backspace"]
+# Shows the definition of the word being typed when hints are not always shown.
+hint = "ctrl+t"
+# Says the word again in dictation mode.
+replay = "ctrl+r"
+# Opens the list of everything sona can do, from any page.
+palette = "ctrl+k"
+# Shows these keys and where they work, from any page.
+help = "f1"
+
+# A `sona serve` server to take challenges from with --challenge and to send
+# results to from the results page, and the name results are sent under.
+[server]
+# url = "http://example.com:7878"
+name = "jan"
+
+# Sounds played while typing, when sona is built with the "sound" feature.
+[sound]
+enabled = false
+# From 0.0 to 1.0.
+volume = 0.5
+# "click", "thock" or "beep", or the name of a folder under sounds/ next to
+# this file holding key.wav and error.wav.
+pack = "click"
+
+# Sitelen pona glyphs drawn as images beside the hint of the word being typed,
+# when sona is built with the "graphics" feature.
+[glyphs]
+# "kitty" for the kitty graphics protocol, "sixel", "auto" for kitty in the
+# terminals known to have it, or "off". Terminals without either show text.
+images = "off"
+# Glyphs are cut from simple line drawings built into sona, or from a binary PGM
+# image made from a font you like better: dark on light in square cells 16 to a
+# row, in the order of their UCSUR codepoints from U+F1900.
+# atlas = "/home/jan/sitelen-pona.pgm"
+
+# More color schemes can be added as tables under themes, for example:
+# [themes.mine]
+# correct = "white"
+# error = "#ff5555"
+# excess = "yellow"
+# blank = "dark-gray"
+# hint = "gray"
+# caret = "cyan"
+"##;
+
+/// How the caret at the typing position is drawn.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Caret {
+    #[default]
+    Block,
+    Underline,
+    /// A blinking block, for terminals that support blinking text.
+    Blink,
+}
+
+impl Caret {
+    pub fn apply(
+        &self,
+        style: ratatui::style::Style,
+        color: ratatui::style::Color,
+    ) -> ratatui::style::Style {
+        use ratatui::style::Stylize;
+
+        match self {
+            Caret::Block => style.bg(color).reversed(),
+            Caret::Underline => style.underline_color(color).underlined(),
+            Caret::Blink => style.bg(color).reversed().slow_blink(),
+        }
+    }
+}
+
+/// Where the text being typed goes on wide terminals.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Layout {
+    /// Widest the text gets in columns, with 0 for no limit.
+    pub max_width: u16,
+    pub center: bool,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            max_width: 80,
+            center: true,
+        }
+    }
+}
+
+impl Layout {
+    /// The part of `area` the text is drawn in.
+    pub fn apply(&self, area: ratatui::layout::Rect) -> ratatui::layout::Rect {
+        let width = match self.max_width {
+            0 => area.width,
+            max => area.width.min(max),
+        };
+        let flex = match self.center {
+            true => ratatui::layout::Flex::Center,
+            false => ratatui::layout::Flex::Start,
+        };
+        let [area] =
+            ratatui::layout::Layout::horizontal([ratatui::layout::Constraint::Length(width)])
+                .flex(flex)
+                .areas(area);
+
+        area
+    }
+}
+
+/// Where shared challenges come from.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Server {
+    pub url: Option<String>,
+    /// Name results are sent under.
+    pub name: String,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self {
+            url: None,
+            name: "jan".to_string(),
+        }
+    }
+}
+
+/// How glyphs are drawn as images.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Images {
+    Off,
+    /// Kitty in the terminals known to have it, and off in the rest.
+    Auto,
+    Kitty,
+    Sixel,
+}
+
+/// Sitelen pona glyphs drawn as images, which only do anything with the "graphics" feature.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Glyphs {
+    pub images: Images,
+    /// A PGM image of the glyphs 16 to a row in UCSUR order, used instead of the built-in one.
+    pub atlas: Option<std::path::PathBuf>,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self {
+            images: Images::Off,
+            atlas: None,
+        }
+    }
+}
+
+/// Sounds played on keystrokes and mistakes, which only do anything with the "sound" feature.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Sound {
+    pub enabled: bool,
+    pub volume: f32,
+    /// A built-in pack, or a folder under the config dir's sounds/ with key.wav and error.wav.
+    pub pack: String,
+}
+
+impl Default for Sound {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            volume: 0.5,
+            pack: "click".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Config {
+    pub caret: Caret,
+    pub theme: String,
+    pub marks: crate::theme::Marks,
+    pub mouse: bool,
+    /// Seconds without a key before a test pauses itself, with 0 for never.
+    pub idle: u64,
+    pub storage: crate::storage::Backend,
+    pub word_of_the_day: bool,
+    pub banned: Vec<String>,
+    pub quotes: Option<std::path::PathBuf>,
+    pub packs: Vec<std::path::PathBuf>,
+    pub words: crate::words::WordReq,
+    pub layout: Layout,
+    pub keys: crate::keys::Keys,
+    pub server: Server,
+    pub sound: Sound,
+    pub glyphs: Glyphs,
+    pub themes: std::collections::HashMap<String, crate::theme::Theme>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            caret: Caret::Block,
+            theme: "default".to_string(),
+            marks: crate::theme::Marks::Color,
+            mouse: true,
+            idle: 10,
+            storage: crate::storage::Backend::Files,
+            word_of_the_day: true,
+            banned: Vec::new(),
+            quotes: None,
+            packs: Vec::new(),
+            words: crate::words::WordReq::default(),
+            layout: Layout::default(),
+            keys: crate::keys::Keys::default(),
+            server: Server::default(),
+            sound: Sound::default(),
+            glyphs: Glyphs::default(),
+            themes: std::collections::HashMap::new(),
+        }
+    }
+}
+
+pub static CONFIG: std::sync::LazyLock<Config> = std::sync::LazyLock::new(Config::load);
+
+impl Config {
+    fn path() -> Option<std::path::PathBuf> {
+        if crate::simulate::is_headless() {
+            return None;
+        }
+        let dirs = directories::ProjectDirs::from("", "", "sona")?;
+        std::fs::create_dir_all(dirs.config_dir()).ok()?;
+
+        Some(dirs.config_dir().join("config.toml"))
+    }
+
+    /// Reads the config file, creating it with the defaults if it does not exist yet. A config that
+    /// can't be read ends sona with what is wrong with it, rather than it being quietly ignored.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        if !path.exists() {
+            let _ = std::fs::write(&path, DEFAULT_CONFIG);
+        }
+
+        let Ok(toml) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        toml::from_str(&toml).unwrap_or_else(|err| {
+            eprintln!("sona: could not read {}: {err}", path.display());
+            std::process::exit(1);
+        })
+    }
+}
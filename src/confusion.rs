@@ -0,0 +1,242 @@
+/// Pairs shown in the ranking beside the matrix.
+const TOP: usize = 10;
+/// Words a drill needs before words with only the expected letter are let in too.
+const DRILL_WORDS: usize = 5;
+
+/// How often each letter was typed in place of each other letter, across every test.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Confusions {
+    /// Counts keyed by the letter that should have been typed and then the letter typed instead,
+    /// as strings since TOML keys have to be.
+    pub pairs: std::collections::BTreeMap<String, std::collections::BTreeMap<String, usize>>,
+}
+
+pub static CONFUSIONS: std::sync::LazyLock<std::sync::Mutex<Confusions>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Confusions::load()));
+
+impl Confusions {
+    /// Loads the confusions from disk, starting with none.
+    pub fn load() -> Self {
+        crate::storage::load("confusions.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("confusions.toml", self);
+    }
+
+    /// Counts every wrong letter typed in a test, including the ones corrected afterwards.
+    pub fn record(&mut self, recording: &crate::replay::Recording) {
+        let targets: Vec<&str> = recording.target.split_terminator(' ').collect();
+        let mut input = String::new();
+
+        recording.strokes.iter().for_each(|(_, stroke)| {
+            if let crate::replay::Stroke::Char(typed) = stroke {
+                let (word, char) = crate::text::caret(&input);
+                let expected = targets
+                    .get(word)
+                    .and_then(|target| crate::text::graphemes(target).nth(char))
+                    .and_then(|grapheme| grapheme.chars().next());
+
+                if let Some(expected) =
+                    expected.filter(|expected| expected != typed && *typed != ' ')
+                {
+                    *self
+                        .pairs
+                        .entry(expected.to_ascii_lowercase().to_string())
+                        .or_default()
+                        .entry(typed.to_ascii_lowercase().to_string())
+                        .or_default() += 1;
+                }
+            }
+            stroke.apply(&mut input);
+        });
+    }
+
+    /// Every pair with its count, most confused first.
+    fn ranked(&self) -> Vec<(char, char, usize)> {
+        let mut pairs: Vec<(char, char, usize)> = self
+            .pairs
+            .iter()
+            .flat_map(|(expected, typed)| {
+                typed.iter().filter_map(|(typed, count)| {
+                    Some((expected.chars().next()?, typed.chars().next()?, *count))
+                })
+            })
+            .collect();
+        pairs.sort_by(|a, b| b.2.cmp(&a.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+
+        pairs
+    }
+}
+
+/// A matrix of the letters expected against the letters typed, with the most confused pairs
+/// ranked beside it to pick one to drill.
+pub struct Matrix {
+    settings: crate::words::WordReq,
+    /// Letters that show up in any pair, in order.
+    letters: Vec<char>,
+    counts: std::collections::HashMap<(char, char), usize>,
+    ranked: Vec<(char, char, usize)>,
+    selected: usize,
+}
+
+impl Matrix {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let ranked = CONFUSIONS.lock().unwrap().ranked();
+        let letters: std::collections::BTreeSet<char> = ranked
+            .iter()
+            .flat_map(|(expected, typed, _)| [*expected, *typed])
+            .collect();
+
+        Self {
+            settings,
+            letters: letters.into_iter().collect(),
+            counts: ranked
+                .iter()
+                .map(|(expected, typed, count)| ((*expected, *typed), *count))
+                .collect(),
+            ranked: ranked.into_iter().take(TOP).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Settings for a test of the words that have both letters of a pair, or the expected letter
+    /// when too few have both.
+    fn drill(&self, expected: char, typed: char) -> crate::words::WordReq {
+        let words = crate::words::candidates(&self.settings);
+        let mut only: Vec<String> = words
+            .iter()
+            .filter(|word| word.word.contains(expected) && word.word.contains(typed))
+            .map(|word| word.id.clone())
+            .collect();
+        if only.len() < DRILL_WORDS {
+            only = words
+                .iter()
+                .filter(|word| word.word.contains(expected))
+                .map(|word| word.id.clone())
+                .collect();
+        }
+
+        crate::words::WordReq {
+            mode: crate::words::Mode::Words,
+            only,
+            ..self.settings.clone()
+        }
+    }
+
+    fn matrix<'a>(&self) -> ratatui::text::Text<'a> {
+        use ratatui::style::Stylize;
+
+        let most = self.ranked.first().map_or(1, |(_, _, count)| *count);
+        let mut text = ratatui::text::Text::default();
+
+        let mut header = ratatui::text::Line::from("typed → ".dark_gray());
+        self.letters
+            .iter()
+            .for_each(|typed| header.push_span(format!("{typed:>3}").dark_gray()));
+        text.push_line(header);
+
+        self.letters.iter().for_each(|expected| {
+            let mut line = ratatui::text::Line::from(format!("{expected:>7} ").dark_gray());
+            self.letters.iter().for_each(|typed| {
+                let count = self.counts.get(&(*expected, *typed)).copied().unwrap_or(0);
+                let cell = match count {
+                    0 => format!("{:>3}", "·").dark_gray(),
+                    count => {
+                        // Redder the more often the pair is confused, relative to the worst one.
+                        let heat = (count as f64 / most as f64 * 200.0) as u8 + 55;
+                        format!("{count:>3}").fg(ratatui::style::Color::Rgb(heat, 80, 80))
+                    }
+                };
+                line.push_span(cell);
+            });
+            text.push_line(line);
+        });
+        text.push_line("expected ↓".dark_gray());
+
+        text
+    }
+}
+
+impl crate::flow::Store for Matrix {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = self.ranked.len().max(1);
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('q') {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % rows,
+            KeyCode::Enter => {
+                let (expected, typed, _) = self.ranked.get(self.selected)?;
+
+                return Some(crate::flow::State::Game {
+                    settings: self.drill(*expected, *typed),
+                });
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [body, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+        let [matrix, ranking] = ratatui::layout::Layout::horizontal([
+            ratatui::layout::Constraint::Length(8 + self.letters.len() as u16 * 3),
+            ratatui::layout::Constraint::Min(0),
+        ])
+        .spacing(4)
+        .areas(body);
+
+        frame.render_widget(ratatui::widgets::Paragraph::new(self.matrix()), matrix);
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line("most confused".bold());
+        self.ranked
+            .iter()
+            .enumerate()
+            .for_each(|(index, (expected, typed, count))| {
+                let line = ratatui::text::Line::from(format!(
+                    "{typed} for {expected}   {count} {}",
+                    if *count == 1 { "time" } else { "times" }
+                ));
+                match index == self.selected {
+                    true => text.push_line(line.reversed()),
+                    false => text.push_line(line),
+                }
+            });
+        frame.render_widget(ratatui::widgets::Paragraph::new(text), ranking);
+
+        let help_line = match self.ranked.is_empty() {
+            true => "no letters confused yet   q: back",
+            false => "up/down: select   enter: drill the pair   q: back",
+        };
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(help_line.dark_gray()),
+            help,
+        );
+    }
+}
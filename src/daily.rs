@@ -0,0 +1,85 @@
+/// Length of the daily challenge in words.
+const WORDS: usize = 30;
+
+/// Settings of today's daily challenge, the same for everyone on the same day in UTC.
+/// Only how the test is shown is kept from the given settings.
+pub fn settings(base: &crate::words::WordReq) -> crate::words::WordReq {
+    crate::words::WordReq {
+        lang: base.lang.clone(),
+        mode: crate::words::Mode::Daily,
+        sitelen_pona: base.sitelen_pona,
+        keyboard: base.keyboard,
+        ghost: base.ghost,
+        hint: base.hint,
+        hint_delay: base.hint_delay,
+        n: WORDS,
+        // Spread consecutive days apart so their seeds don't look alike.
+        seed: Some(crate::stats::today().wrapping_mul(0x9e37_79b9_7f4a_7c15)),
+        ..Default::default()
+    }
+}
+
+/// Days in a row the daily challenge has been done, counting today or, if not done yet, yesterday.
+pub fn streak(history: &crate::history::History) -> usize {
+    let days: std::collections::HashSet<u64> = history
+        .tests
+        .iter()
+        .filter(|entry| entry.settings.mode == crate::words::Mode::Daily)
+        .map(|entry| entry.time / 86400)
+        .collect();
+
+    let today = crate::stats::today();
+    let last = match days.contains(&today) {
+        true => today,
+        false => today.saturating_sub(1),
+    };
+
+    (0..=last)
+        .rev()
+        .take_while(|day| days.contains(day))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn banned_words_stay_in_the_daily_challenge() {
+        let settings = super::settings(&crate::words::WordReq::default());
+        let word = crate::words::BUILT_IN
+            .iter()
+            .find(|word| word.usage_category == crate::words::UsageCategory::core)
+            .unwrap();
+
+        crate::banned::BANNED.lock().unwrap().toggle(&word.id);
+        let candidates = crate::words::candidates(&settings);
+        crate::banned::BANNED.lock().unwrap().toggle(&word.id);
+
+        assert!(candidates.iter().any(|candidate| candidate.id == word.id));
+    }
+
+    #[test]
+    fn same_words_whatever_the_settings() {
+        let words = |base: crate::words::WordReq| -> Vec<String> {
+            let settings = super::settings(&base);
+            let mut rng = crate::words::seeded_rng(settings.seed.unwrap());
+            crate::words::subset(&settings, &mut rng)
+                .iter()
+                .map(|word| word.id.clone())
+                .collect()
+        };
+
+        let first = words(Default::default());
+        assert_eq!(first.len(), super::WORDS);
+        assert_eq!(
+            words(crate::words::WordReq {
+                n: 5,
+                core: false,
+                sandbox: true,
+                lang: "eo".to_string(),
+                seed: Some(1),
+                ..Default::default()
+            }),
+            first
+        );
+    }
+}
@@ -0,0 +1,258 @@
+/// Tests taken into the accuracy trend, the most recent ones.
+const TREND: usize = 100;
+
+/// How one mode has gone over every test taken in it.
+struct ModeStats {
+    mode: crate::words::Mode,
+    tests: usize,
+    average_wpm: f64,
+    best_wpm: f64,
+}
+
+/// Lifetime totals, speed per mode, personal bests, the accuracy trend and how many words of each
+/// usage category are mastered.
+pub struct Dashboard {
+    settings: crate::words::WordReq,
+    tests: usize,
+    seconds: f64,
+    keystrokes: usize,
+    modes: Vec<ModeStats>,
+    records: Vec<(String, crate::records::Best)>,
+    /// Accuracy of each recent test, and the average of the ten tests up to it.
+    accuracy: Vec<(f64, f64)>,
+    average_accuracy: Vec<(f64, f64)>,
+    /// Mastered and total words of each usage category that has any words.
+    mastery: Vec<(crate::words::UsageCategory, usize, usize)>,
+}
+
+impl Dashboard {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let history = crate::history::HISTORY.lock().unwrap();
+        let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+
+        let modes = crate::words::Mode::ALL
+            .iter()
+            .filter(|mode| mode.is_timed_typing())
+            .filter_map(|mode| {
+                let wpms: Vec<f64> = history
+                    .tests
+                    .iter()
+                    .filter(|entry| entry.settings.mode == *mode)
+                    .map(|entry| entry.wpm)
+                    .collect();
+
+                (!wpms.is_empty()).then(|| ModeStats {
+                    mode: *mode,
+                    tests: wpms.len(),
+                    average_wpm: wpms.iter().sum::<f64>() / wpms.len() as f64,
+                    best_wpm: wpms.iter().copied().fold(0.0, f64::max),
+                })
+            })
+            .collect();
+
+        let recent: Vec<f64> = history
+            .tests
+            .iter()
+            .rev()
+            .take(TREND)
+            .rev()
+            .map(|entry| entry.accuracy)
+            .collect();
+        let accuracy = recent
+            .iter()
+            .enumerate()
+            .map(|(index, accuracy)| ((index + 1) as f64, *accuracy))
+            .collect();
+        let average_accuracy = (0..recent.len())
+            .map(|index| {
+                let window = &recent[index.saturating_sub(9)..=index];
+                (
+                    (index + 1) as f64,
+                    window.iter().sum::<f64>() / window.len() as f64,
+                )
+            })
+            .collect();
+
+        let mastery = [
+            crate::words::UsageCategory::core,
+            crate::words::UsageCategory::common,
+            crate::words::UsageCategory::uncommon,
+            crate::words::UsageCategory::obscure,
+            crate::words::UsageCategory::sandbox,
+        ]
+        .into_iter()
+        .map(|category| {
+            let words: Vec<&crate::words::WordData> = crate::words::WORDS
+                .iter()
+                .filter(|word| word.usage_category == category)
+                .collect();
+            let mastered = words
+                .iter()
+                .filter(|word| word_errors.mastered(&word.id))
+                .count();
+
+            (category, mastered, words.len())
+        })
+        .filter(|(_, _, total)| *total > 0)
+        .collect();
+
+        Self {
+            settings,
+            tests: history.tests.len(),
+            seconds: history.tests.iter().map(|entry| entry.elapsed).sum(),
+            keystrokes: history.tests.iter().map(|entry| entry.keystrokes).sum(),
+            modes,
+            records: crate::records::RECORDS
+                .lock()
+                .unwrap()
+                .bests
+                .iter()
+                .map(|(key, best)| (key.clone(), best.clone()))
+                .collect(),
+            accuracy,
+            average_accuracy,
+            mastery,
+        }
+    }
+
+    fn render_mastery(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        use ratatui::style::Stylize;
+
+        let rows = ratatui::layout::Layout::vertical(
+            self.mastery
+                .iter()
+                .map(|_| ratatui::layout::Constraint::Length(1)),
+        )
+        .split(area);
+
+        self.mastery
+            .iter()
+            .zip(rows.iter())
+            .for_each(|((category, mastered, total), row)| {
+                let gauge = ratatui::widgets::LineGauge::default()
+                    .label(format!(
+                        "{:<9}{mastered:>4}/{total:<4}",
+                        format!("{category:?}")
+                    ))
+                    .ratio(*mastered as f64 / *total as f64)
+                    .filled_style(ratatui::style::Style::new().green())
+                    .unfilled_style(ratatui::style::Style::new().dark_gray());
+
+                frame.render_widget(gauge, *row);
+            });
+    }
+
+    fn render_trend(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        use ratatui::style::Stylize;
+
+        let bottom = self
+            .accuracy
+            .iter()
+            .map(|(_, accuracy)| *accuracy)
+            .fold(100.0, f64::min)
+            .floor();
+        let tests = self.accuracy.len() as f64;
+        let theme = crate::theme::current();
+
+        let chart = ratatui::widgets::Chart::new(vec![
+            ratatui::widgets::Dataset::default()
+                .marker(ratatui::symbols::Marker::Dot)
+                .graph_type(ratatui::widgets::GraphType::Scatter)
+                .style(ratatui::style::Style::new().fg(theme.blank))
+                .data(&self.accuracy),
+            ratatui::widgets::Dataset::default()
+                .name("average of 10")
+                .marker(ratatui::symbols::Marker::Braille)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(ratatui::style::Style::new().fg(theme.correct))
+                .data(&self.average_accuracy),
+        ])
+        .x_axis(
+            ratatui::widgets::Axis::default()
+                .title("test".dark_gray())
+                .bounds([1.0, tests])
+                .labels(["1".to_string(), format!("{tests}")]),
+        )
+        .y_axis(
+            ratatui::widgets::Axis::default()
+                .title("accuracy".dark_gray())
+                .bounds([bottom, 100.0])
+                .labels([format!("{bottom}%"), "100%".to_string()]),
+        );
+
+        frame.render_widget(chart, area);
+    }
+}
+
+impl crate::flow::Store for Dashboard {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        match action.char() {
+            _ if action.is(&crate::config::CONFIG.keys.quit) => {
+                Some(crate::flow::State::Settings {
+                    settings: self.settings.clone(),
+                })
+            }
+            Some('q') => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line(format!("tests: {}", self.tests).bold());
+        text.push_line(format!(
+            "time typed: {:.0}h {:.0}m",
+            (self.seconds / 3600.0).floor(),
+            (self.seconds / 60.0).floor() % 60.0
+        ));
+        text.push_line(format!("keystrokes: {}", self.keystrokes));
+        text.push_line(ratatui::text::Line::default());
+        text.push_line(
+            format!("{:<10}{:>6}{:>9}{:>6}", "mode", "tests", "average", "best").dark_gray(),
+        );
+        self.modes.iter().for_each(|stats| {
+            text.push_line(format!(
+                "{:<10}{:>6}{:>9.0}{:>6.0}",
+                format!("{:?}", stats.mode).to_lowercase(),
+                stats.tests,
+                stats.average_wpm,
+                stats.best_wpm
+            ));
+        });
+        if !self.records.is_empty() {
+            text.push_line(ratatui::text::Line::default());
+            text.push_line(
+                format!("{:<32}{:>6}{:>10}", "personal bests", "wpm", "accuracy").dark_gray(),
+            );
+            self.records.iter().for_each(|(key, best)| {
+                text.push_line(format!("{key:<32}{:>6.0}{:>9.1}%", best.wpm, best.accuracy));
+            });
+        }
+        text.push_line(ratatui::text::Line::default());
+        text.push_line("words mastered".dark_gray());
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [summary, mastery, trend, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(text.height() as u16),
+            ratatui::layout::Constraint::Length(self.mastery.len() as u16 + 1),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        frame.render_widget(ratatui::widgets::Paragraph::new(text), summary);
+        self.render_mastery(frame, mastery);
+        if self.accuracy.len() > 1 {
+            self.render_trend(frame, trend);
+        }
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new("q: back".dark_gray()),
+            help,
+        );
+    }
+}
@@ -0,0 +1,221 @@
+/// Finds a word by its id or spelling, ignoring case.
+pub fn lookup(query: &str) -> Option<&'static crate::words::WordData> {
+    crate::words::all()
+        .into_iter()
+        .find(|word| word.id.eq_ignore_ascii_case(query) || word.word.eq_ignore_ascii_case(query))
+}
+
+/// Width of the longest ku usage bar, in characters.
+const BAR: usize = 20;
+
+/// Everything known about a word as lines of text, with definitions in the given language.
+/// The pu and ku data are left to `pu` and `ku`, which are laid out as tables.
+pub fn details(word: &crate::words::WordData, lang: &str) -> Vec<String> {
+    let mut lines = vec![match word.deprecated {
+        true => format!("{} ({:?}, deprecated)", word.word, word.usage_category),
+        false => format!("{} ({:?})", word.word, word.usage_category),
+    }];
+
+    if let Some(definition) = word.definition(lang) {
+        lines.push(format!("definition: {definition}"));
+    }
+
+    if let Some(commentary) = word.commentary.as_ref().filter(|text| !text.is_empty()) {
+        lines.push(format!("commentary: {commentary}"));
+    }
+
+    if let Some(pack) = &word.pack {
+        lines.push(format!("pack: {pack}"));
+    }
+
+    if crate::banned::BANNED.lock().unwrap().contains(&word.id) {
+        lines.push("banned: never shown in tests".to_string());
+    }
+
+    lines
+}
+
+/// The entries of the word in pu by language, the given language first, then English, then the
+/// rest in alphabetical order.
+pub fn pu<'a>(word: &'a crate::words::WordData, lang: &str) -> Vec<(&'a str, &'a str)> {
+    let mut entries: Vec<(&str, &str)> = word
+        .pu_verbatim
+        .iter()
+        .flatten()
+        .map(|(lang, text)| (lang.as_str(), text.as_str()))
+        .collect();
+    entries.sort_by_key(|(entry, _)| (*entry != lang, *entry != "en", *entry));
+
+    entries
+}
+
+/// How often each translation was given for the word in ku, most given first.
+pub fn ku(word: &crate::words::WordData) -> Vec<(&str, u16)> {
+    let mut usage: Vec<(&str, u16)> = word
+        .ku_data
+        .iter()
+        .flatten()
+        .map(|(translation, percent)| (translation.as_str(), *percent))
+        .collect();
+    usage.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    usage
+}
+
+/// A bar as long as the percentage is of `BAR`, with at least a sliver for anything above zero.
+fn bar(percent: u16) -> String {
+    let full = (percent as usize * BAR).div_ceil(100).min(BAR);
+    "█".repeat(full)
+}
+
+/// The pu entries as a two column table, one line each.
+fn pu_table(word: &crate::words::WordData, lang: &str) -> Vec<(String, String)> {
+    pu(word, lang)
+        .into_iter()
+        .map(|(lang, text)| (format!("{lang:<5} "), text.to_string()))
+        .collect()
+}
+
+/// The ku usage as bars lined up after the translations, one line each.
+fn ku_bars(word: &crate::words::WordData) -> Vec<String> {
+    let usage = ku(word);
+    let width = usage
+        .iter()
+        .map(|(text, _)| text.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    usage
+        .iter()
+        .map(|(text, percent)| format!("{text:<width$}  {:<BAR$} {percent:>3}%", bar(*percent)))
+        .collect()
+}
+
+/// Draws everything known about a word, with its pu and ku data in panels of their own below.
+/// The ku panel takes whatever room is left, since common words have dozens of translations.
+pub fn render(
+    frame: &mut ratatui::Frame,
+    area: ratatui::layout::Rect,
+    word: &crate::words::WordData,
+    lang: &str,
+) {
+    use ratatui::style::Stylize;
+
+    let info = details(word, lang);
+    let pu = pu_table(word, lang);
+    let ku = ku_bars(word);
+
+    // Text is as tall as its lines once wrapped.
+    let height = |lines: &mut dyn Iterator<Item = String>, width: u16| -> u16 {
+        let width = width.max(1) as usize;
+        lines
+            .map(|line| unicode_width::UnicodeWidthStr::width(line.as_str()).div_ceil(width))
+            .map(|rows| rows.max(1) as u16)
+            .sum()
+    };
+    let panel = |lines: usize, height: u16| match lines {
+        0 => 0,
+        _ => height + 2,
+    };
+    let pu_height = panel(
+        pu.len(),
+        height(
+            &mut pu.iter().map(|(lang, text)| lang.clone() + text),
+            area.width.saturating_sub(2),
+        ),
+    );
+    let [info_area, pu_area, ku_area] = ratatui::layout::Layout::vertical([
+        ratatui::layout::Constraint::Length(height(&mut info.iter().cloned(), area.width)),
+        ratatui::layout::Constraint::Length(pu_height),
+        ratatui::layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+
+    frame.render_widget(
+        ratatui::widgets::Paragraph::new(
+            info.into_iter()
+                .map(ratatui::text::Line::from)
+                .collect::<Vec<_>>(),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: false }),
+        info_area,
+    );
+
+    if !pu.is_empty() {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                pu.into_iter()
+                    .map(|(lang, text)| ratatui::text::Line::from(vec![lang.bold(), text.into()]))
+                    .collect::<Vec<_>>(),
+            )
+            .wrap(ratatui::widgets::Wrap { trim: false })
+            .block(ratatui::widgets::Block::bordered().title(" pu ")),
+            pu_area,
+        );
+    }
+    if !ku.is_empty() {
+        let ku_area = ku_area.intersection(ratatui::layout::Rect {
+            height: panel(ku.len(), ku.len() as u16),
+            ..ku_area
+        });
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                ku.into_iter()
+                    .map(|line| ratatui::text::Line::from(line).green())
+                    .collect::<Vec<_>>(),
+            )
+            .block(ratatui::widgets::Block::bordered().title(" ku usage ")),
+            ku_area,
+        );
+    }
+}
+
+/// Prints a word from the dictionary, as json if asked to.
+pub fn run(query: &str, json: bool, lang: &str) -> Result<(), String> {
+    let word = lookup(query).ok_or_else(|| format!("no word {query:?} in the dictionary"))?;
+
+    match json {
+        true => println!("{}", serde_json::to_string_pretty(word).unwrap()),
+        false => {
+            details(word, lang)
+                .iter()
+                .for_each(|line| println!("{line}"));
+
+            let (pu, ku) = (pu_table(word, lang), ku_bars(word));
+            if !pu.is_empty() {
+                println!("\npu:");
+                pu.iter()
+                    .for_each(|(lang, text)| println!("  {lang}{text}"));
+            }
+            if !ku.is_empty() {
+                println!("\nku usage:");
+                ku.iter().for_each(|line| println!("  {line}"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Scores how well a query matches text when its characters appear in order, ignoring case.
+/// Consecutive characters and matches near the start score higher, and None means no match.
+pub fn fuzzy(query: &str, text: &str) -> Option<i32> {
+    let mut text = text.chars().map(|c| c.to_ascii_lowercase()).enumerate();
+    let mut score = 0;
+    let mut last: Option<usize> = None;
+
+    for wanted in query.chars().map(|c| c.to_ascii_lowercase()) {
+        let (index, _) = text.find(|(_, c)| *c == wanted)?;
+
+        score += match last {
+            Some(last) if index == last + 1 => 8,
+            _ => 1,
+        };
+        if index == 0 {
+            score += 4;
+        }
+        last = Some(index);
+    }
+
+    Some(score * 100 - last.unwrap_or_default() as i32)
+}
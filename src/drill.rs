@@ -0,0 +1,43 @@
+/// The letters of toki pona, offered one at a time on the settings page.
+pub const LETTERS: [&str; 14] = [
+    "a", "e", "i", "j", "k", "l", "m", "n", "o", "p", "s", "t", "u", "w",
+];
+/// The densest words a drill draws from.
+const POOL: usize = 20;
+
+/// How much of a word is made of the drilled letters, from 0 to 1.
+fn density(word: &str, letters: &str) -> f64 {
+    let length = word.chars().count().max(1);
+    let drilled = word
+        .chars()
+        .filter(|c| letters.contains(c.to_ascii_lowercase()))
+        .count();
+
+    drilled as f64 / length as f64
+}
+
+/// `settings.n` words drawn from the allowed words densest in `settings.letters`, denser words
+/// more often. The pool is small, so words come up again and again.
+pub fn subset(
+    settings: &crate::words::WordReq,
+    rng: &mut impl rand::Rng,
+) -> Vec<&'static crate::words::WordData> {
+    use rand::seq::SliceRandom;
+
+    let mut words: Vec<(&crate::words::WordData, f64)> = crate::words::candidates(settings)
+        .into_iter()
+        .map(|word| (word, density(&word.word, &settings.letters)))
+        .filter(|(_, density)| *density > 0.0)
+        .collect();
+    words.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.id.cmp(&b.0.id)));
+    words.truncate(POOL);
+
+    (0..settings.n)
+        .filter_map(|_| {
+            words
+                .choose_weighted(&mut *rng, |(_, density)| density * density)
+                .ok()
+                .map(|(word, _)| *word)
+        })
+        .collect()
+}
@@ -0,0 +1,181 @@
+/// The fields of the form, in the order they are drawn.
+const FIELDS: [&str; 2] = ["word", "definition"];
+
+/// Form for making a nimi sin or changing one made before, saved to the personal word pack as a
+/// sandbox word.
+pub struct Editor {
+    settings: crate::words::WordReq,
+    /// The text of each field in FIELDS.
+    fields: [String; 2],
+    selected: usize,
+    /// Whether a word made before is being changed, which keeps its spelling since that is its id.
+    editing: bool,
+    /// What happened on the last save.
+    message: Option<Result<String, String>>,
+}
+
+impl Editor {
+    /// Opens the form empty, or filled in with the word with the given id.
+    pub fn new(settings: crate::words::WordReq, id: Option<String>) -> Self {
+        let word = id.and_then(|id| crate::words::all().into_iter().find(|word| word.id == id));
+
+        Self {
+            editing: word.is_some(),
+            settings,
+            fields: [
+                word.map(|word| word.word.clone()).unwrap_or_default(),
+                word.and_then(|word| word.definitions.clone())
+                    .unwrap_or_default(),
+            ],
+            selected: word.map_or(0, |_| 1),
+            message: None,
+        }
+    }
+
+    /// The word as filled in, or what is wrong with it.
+    fn word(&self) -> Result<crate::words::WordData, String> {
+        let [word, definition] = self.fields.clone().map(|field| field.trim().to_string());
+
+        if word.is_empty() {
+            return Err("the word is empty".to_string());
+        }
+        if word.contains(char::is_whitespace) {
+            return Err("the word has a space in it".to_string());
+        }
+        if definition.is_empty() {
+            return Err("the definition is empty".to_string());
+        }
+
+        Ok(crate::words::WordData {
+            id: word.clone(),
+            usage_category: crate::words::UsageCategory::sandbox,
+            word,
+            deprecated: false,
+            ku_data: None,
+            pu_verbatim: None,
+            commentary: None,
+            definitions: Some(definition),
+            translations: std::collections::HashMap::new(),
+            audio: Vec::new(),
+            pack: None,
+        })
+    }
+
+    /// Saves the word, turning on sandbox words so it shows up in tests, and empties the form for
+    /// the next one unless a word made before is being changed.
+    fn save(&mut self) {
+        self.message = Some(self.word().and_then(|word| {
+            let saved = format!("saved {}", word.word);
+            crate::words::save_personal(word)?;
+            Ok(saved)
+        }));
+
+        if let Some(Ok(_)) = self.message {
+            self.settings.sandbox = true;
+        }
+        if let (Some(Ok(_)), false) = (&self.message, self.editing) {
+            self.fields = Default::default();
+            self.selected = 0;
+        }
+    }
+}
+
+impl crate::flow::Store for Editor {
+    fn settings(&self) -> Option<crate::words::WordReq> {
+        Some(self.settings.clone())
+    }
+
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        // Fields are drawn one per line below the top padding.
+        if let Some(click) = action.click() {
+            if let Some(row) = (click.y as usize)
+                .checked_sub(1)
+                .filter(|row| *row < FIELDS.len())
+            {
+                self.selected = row.max(self.editing as usize);
+            }
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::BackTab if !self.editing => {
+                self.selected = (self.selected + FIELDS.len() - 1) % FIELDS.len()
+            }
+            KeyCode::Down | KeyCode::Tab if !self.editing => {
+                self.selected = (self.selected + 1) % FIELDS.len()
+            }
+            KeyCode::Enter => self.save(),
+            KeyCode::Backspace => {
+                self.fields[self.selected].pop();
+            }
+            _ => {
+                if let Some(c) = action.char() {
+                    self.fields[self.selected].push(c);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let mut lines: Vec<ratatui::text::Line> = FIELDS
+            .iter()
+            .zip(&self.fields)
+            .enumerate()
+            .map(|(index, (name, text))| {
+                let label = format!("{:<12}", format!("{name}:"));
+                match index == self.selected {
+                    true => ratatui::text::Line::from(vec![
+                        label.reversed(),
+                        format!(" {text}_").into(),
+                    ]),
+                    false if index == 0 && self.editing => {
+                        ratatui::text::Line::from(format!("{label} {text}")).dark_gray()
+                    }
+                    false => ratatui::text::Line::from(format!("{label} {text}")),
+                }
+            })
+            .collect();
+        lines.push(ratatui::text::Line::from(format!("{:<12} sandbox", "category:")).dark_gray());
+        lines.push(ratatui::text::Line::default());
+
+        match &self.message {
+            Some(Ok(message)) => lines.push(ratatui::text::Line::from(message.clone()).green()),
+            Some(Err(err)) => lines.push(ratatui::text::Line::from(err.clone()).red()),
+            None => lines.push(ratatui::text::Line::default()),
+        }
+        lines.push(
+            ratatui::text::Line::from(format!(
+                "{}enter: save   {}: back",
+                match self.editing {
+                    true => "",
+                    false => "tab/up/down: field   ",
+                },
+                crate::keys::describe(&crate::config::CONFIG.keys.quit)
+            ))
+            .dark_gray(),
+        );
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false }),
+            block.inner(frame.area()),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+/// File formats history and stats can be exported to.
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+/// Accumulated stats of one word as they are exported.
+#[derive(serde::Serialize)]
+struct WordStats<'a> {
+    id: &'a str,
+    hits: i32,
+    misses: i32,
+    /// Moving average of seconds spent per character.
+    seconds_per_char: Option<f64>,
+}
+
+fn word_stats(word_errors: &crate::stats::WordErrors) -> Vec<WordStats<'_>> {
+    let mut stats: Vec<WordStats> = word_errors
+        .words
+        .iter()
+        .map(|(id, (hits, misses))| WordStats {
+            id,
+            hits: *hits,
+            misses: *misses,
+            seconds_per_char: word_errors.speed.get(id).copied(),
+        })
+        .collect();
+    stats.sort_by(|a, b| a.id.cmp(b.id));
+
+    stats
+}
+
+/// Quotes a csv field when it holds a separator, quote or line break.
+fn field(value: impl std::fmt::Display) -> String {
+    let value = value.to_string();
+
+    match value.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value,
+    }
+}
+
+fn history_csv(history: &crate::history::History) -> String {
+    let mut csv = "date,mode,words,time,wpm,raw_wpm,consistency,accuracy,errors,correct_words,finished_words,keystrokes,elapsed,lang,seed\n".to_string();
+
+    history.tests.iter().for_each(|entry| {
+        let settings = &entry.settings;
+        let row = [
+            field(entry.date()),
+            field(format!("{:?}", settings.mode).to_lowercase()),
+            field(settings.n),
+            field(settings.time),
+            field(entry.wpm),
+            field(entry.raw_wpm),
+            entry.consistency.map(field).unwrap_or_default(),
+            field(entry.accuracy),
+            field(entry.errors),
+            field(entry.words.0),
+            field(entry.words.1),
+            field(entry.keystrokes),
+            field(entry.elapsed),
+            field(&settings.lang),
+            settings.seed.map(field).unwrap_or_default(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    });
+
+    csv
+}
+
+fn words_csv(stats: &[WordStats]) -> String {
+    let mut csv = "id,hits,misses,seconds_per_char\n".to_string();
+
+    stats.iter().for_each(|word| {
+        let row = [
+            field(word.id),
+            field(word.hits),
+            field(word.misses),
+            word.seconds_per_char.map(field).unwrap_or_default(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    });
+
+    csv
+}
+
+/// Writes the test history and per-word stats, returning the files written.
+/// Csv gets one file per table, with the word stats next to `out` as "<name>-words.csv".
+pub fn run(format: Format, out: &std::path::Path) -> Result<Vec<std::path::PathBuf>, String> {
+    let history = crate::history::HISTORY.lock().unwrap();
+    let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+    let stats = word_stats(&word_errors);
+
+    let write = |path: &std::path::Path, contents: String| {
+        std::fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+    };
+
+    match format {
+        Format::Json => {
+            let json = serde_json::json!({
+                "history": history.tests,
+                "words": stats,
+            });
+            write(out, serde_json::to_string_pretty(&json).unwrap())?;
+
+            Ok(vec![out.to_path_buf()])
+        }
+        Format::Csv => {
+            let stem = out.file_stem().unwrap_or_default().to_string_lossy();
+            let words = out.with_file_name(format!("{stem}-words.csv"));
+
+            write(out, history_csv(&history))?;
+            write(&words, words_csv(&stats))?;
+
+            Ok(vec![out.to_path_buf(), words])
+        }
+    }
+}
+
+/// Writes an Anki import file of the words missed more often than `error_rate` or typed slower
+/// than `seconds_per_char`, with the word on the front and its definition and commentary on
+/// the back. Returns how many cards were written.
+pub fn anki(
+    out: &std::path::Path,
+    error_rate: f64,
+    seconds_per_char: f64,
+    lang: &str,
+) -> Result<usize, String> {
+    let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+
+    let weak = |word: &&crate::words::WordData| {
+        let (hits, misses) = word_errors.words.get(&word.id).copied().unwrap_or_default();
+        let missed = hits + misses > 0 && misses as f64 / (hits + misses) as f64 > error_rate;
+        let slow = word_errors
+            .speed
+            .get(&word.id)
+            .is_some_and(|speed| *speed > seconds_per_char);
+
+        missed || slow
+    };
+
+    // Anki reads tabs as field separators and html for line breaks.
+    let clean = |text: &str| text.replace('\t', " ").replace('\n', "<br>");
+
+    let mut tsv = "#separator:tab\n#html:true\n".to_string();
+    let mut cards = 0;
+    crate::words::WORDS.iter().filter(weak).for_each(|word| {
+        let mut back = clean(word.definition(lang).unwrap_or_default());
+        if let Some(commentary) = word.commentary.as_ref().filter(|text| !text.is_empty()) {
+            back.push_str("<br><br>");
+            back.push_str(&clean(commentary));
+        }
+
+        tsv.push_str(&format!("{}\t{back}\n", clean(&word.word)));
+        cards += 1;
+    });
+
+    std::fs::write(out, tsv).map_err(|err| format!("{}: {err}", out.display()))?;
+
+    Ok(cards)
+}
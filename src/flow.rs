@@ -0,0 +1,408 @@
+/// Something that happened which the current page may want to respond to.
+pub enum Action {
+    Key(ratatui::crossterm::event::KeyEvent),
+    /// A click or scroll, when mouse support is turned on.
+    Mouse(ratatui::crossterm::event::MouseEvent),
+    Resize,
+    /// Sent when no event arrived for a while, so timers can move on.
+    Tick,
+}
+
+impl Action {
+    /// Converts a terminal event into an action, ignoring events no page cares about.
+    pub fn from_event(event: ratatui::crossterm::event::Event) -> Option<Self> {
+        match event {
+            ratatui::crossterm::event::Event::Key(key)
+                if key.kind == ratatui::crossterm::event::KeyEventKind::Press =>
+            {
+                Some(Action::Key(key))
+            }
+            // Movement is left out since nothing uses it, and it would redraw on every move.
+            ratatui::crossterm::event::Event::Mouse(mouse)
+                if matches!(
+                    mouse.kind,
+                    ratatui::crossterm::event::MouseEventKind::Down(_)
+                        | ratatui::crossterm::event::MouseEventKind::ScrollUp
+                        | ratatui::crossterm::event::MouseEventKind::ScrollDown
+                ) =>
+            {
+                Some(Action::Mouse(mouse))
+            }
+            ratatui::crossterm::event::Event::Resize(_, _) => Some(Action::Resize),
+            _ => None,
+        }
+    }
+
+    /// The character typed, if this action is a character key press without ctrl or alt held.
+    pub fn char(&self) -> Option<char> {
+        use ratatui::crossterm::event::KeyModifiers;
+
+        match self {
+            Action::Key(ratatui::crossterm::event::KeyEvent {
+                code: ratatui::crossterm::event::KeyCode::Char(c),
+                modifiers,
+                ..
+            }) if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Where the left mouse button was clicked, if this action is such a click.
+    pub fn click(&self) -> Option<ratatui::layout::Position> {
+        match self {
+            Action::Mouse(ratatui::crossterm::event::MouseEvent {
+                kind:
+                    ratatui::crossterm::event::MouseEventKind::Down(
+                        ratatui::crossterm::event::MouseButton::Left,
+                    ),
+                column,
+                row,
+                ..
+            }) => Some(ratatui::layout::Position::new(*column, *row)),
+            _ => None,
+        }
+    }
+
+    /// -1 for a scroll up and 1 for a scroll down, so it can be added to a selection.
+    pub fn scroll(&self) -> Option<isize> {
+        match self {
+            Action::Mouse(mouse) => match mouse.kind {
+                ratatui::crossterm::event::MouseEventKind::ScrollUp => Some(-1),
+                ratatui::crossterm::event::MouseEventKind::ScrollDown => Some(1),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether this action is a key press matching any of the bindings.
+    pub fn is(&self, bindings: &[crate::keys::Binding]) -> bool {
+        match self {
+            Action::Key(key) => bindings.iter().any(|binding| binding.matches(key)),
+            _ => false,
+        }
+    }
+}
+
+/// The pages of the app along with what they need to be built.
+pub enum State {
+    Game {
+        settings: crate::words::WordReq,
+    },
+    Results {
+        settings: crate::words::WordReq,
+        result: Box<crate::results::TestResult>,
+    },
+    Settings {
+        settings: crate::words::WordReq,
+    },
+    History {
+        settings: crate::words::WordReq,
+    },
+    Browse {
+        settings: crate::words::WordReq,
+    },
+    Progress {
+        settings: crate::words::WordReq,
+    },
+    Trophies {
+        settings: crate::words::WordReq,
+    },
+    Dashboard {
+        settings: crate::words::WordReq,
+    },
+    Lessons {
+        settings: crate::words::WordReq,
+    },
+    Review {
+        settings: crate::words::WordReq,
+    },
+    Report {
+        settings: crate::words::WordReq,
+    },
+    Confusions {
+        settings: crate::words::WordReq,
+    },
+    WordOfTheDay {
+        settings: crate::words::WordReq,
+    },
+    /// The form for making a word of one's own, or changing the one with the given id.
+    Editor {
+        settings: crate::words::WordReq,
+        word: Option<String>,
+    },
+    /// Offers to carry on with the test left unfinished last time.
+    Unfinished {
+        settings: crate::words::WordReq,
+    },
+    /// The test left unfinished last time, carried on with.
+    Resume {
+        settings: crate::words::WordReq,
+    },
+    Exit,
+}
+
+impl State {
+    fn into_store(self) -> Option<Box<dyn Store>> {
+        match self {
+            State::Game { settings } => match settings.mode {
+                crate::words::Mode::Zen => Some(Box::new(crate::zen::Zen::new(settings))),
+                crate::words::Mode::Quiz | crate::words::Mode::Dictation => {
+                    Some(Box::new(crate::quiz::Quiz::new(settings)))
+                }
+                crate::words::Mode::Choice => Some(Box::new(crate::choice::Choice::new(settings))),
+                crate::words::Mode::Daily => Some(Box::new(crate::game::Game::new(
+                    crate::daily::settings(&settings),
+                ))),
+                _ => Some(Box::new(crate::game::Game::new(settings))),
+            },
+            State::Results { settings, result } => {
+                Some(Box::new(crate::results::Results::new(settings, *result)))
+            }
+            State::Settings { settings } => {
+                Some(Box::new(crate::settings::Settings::new(settings)))
+            }
+            State::History { settings } => Some(Box::new(crate::history::Browser::new(settings))),
+            State::Browse { settings } => Some(Box::new(crate::browse::Browse::new(settings))),
+            State::Progress { settings } => {
+                Some(Box::new(crate::progress::Progress::new(settings)))
+            }
+            State::Trophies { settings } => {
+                Some(Box::new(crate::achievements::Trophies::new(settings)))
+            }
+            State::Dashboard { settings } => {
+                Some(Box::new(crate::dashboard::Dashboard::new(settings)))
+            }
+            State::Lessons { settings } => Some(Box::new(crate::lessons::Picker::new(settings))),
+            State::Review { settings } => Some(Box::new(crate::review::Queue::new(settings))),
+            State::Report { settings } => Some(Box::new(crate::report::Words::new(settings))),
+            State::Confusions { settings } => {
+                Some(Box::new(crate::confusion::Matrix::new(settings)))
+            }
+            State::WordOfTheDay { settings } => {
+                Some(Box::new(crate::wotd::WordOfTheDay::new(settings)))
+            }
+            State::Editor { settings, word } => {
+                Some(Box::new(crate::editor::Editor::new(settings, word)))
+            }
+            State::Unfinished { settings } => Some(Box::new(crate::session::Offer::new(settings))),
+            State::Resume { settings } => match crate::session::Session::load() {
+                Some(session) => Some(Box::new(crate::game::Game::resume(session))),
+                None => Some(Box::new(crate::settings::Settings::new(settings))),
+            },
+            State::Exit => None,
+        }
+    }
+
+    /// The settings the page is built with, which every page but exiting has.
+    fn settings(&self) -> Option<&crate::words::WordReq> {
+        match self {
+            State::Game { settings }
+            | State::Results { settings, .. }
+            | State::Settings { settings }
+            | State::History { settings }
+            | State::Browse { settings }
+            | State::Progress { settings }
+            | State::Trophies { settings }
+            | State::Dashboard { settings }
+            | State::Lessons { settings }
+            | State::Review { settings }
+            | State::Report { settings }
+            | State::Confusions { settings }
+            | State::WordOfTheDay { settings }
+            | State::Editor { settings, .. }
+            | State::Unfinished { settings }
+            | State::Resume { settings } => Some(settings),
+            State::Exit => None,
+        }
+    }
+}
+
+/// A page that owns its state, updates it from actions and knows how to draw itself.
+pub trait Store {
+    /// Updates the store, returning the state to switch to when the page is done.
+    fn reduce(&mut self, action: &Action) -> Option<State>;
+
+    fn render(&self, frame: &mut ratatui::Frame);
+
+    /// The settings as changed on the page, for pages that change them.
+    fn settings(&self) -> Option<crate::words::WordReq> {
+        None
+    }
+
+    /// How long to wait for an event before sending a tick, shorter for pages that animate.
+    fn tick_rate(&self) -> std::time::Duration {
+        TICK_RATE
+    }
+
+    /// Whether the page changes with time alone, so it is drawn again on ticks and not only
+    /// after events.
+    fn animating(&self) -> bool {
+        false
+    }
+
+    /// Where to show a sitelen pona glyph as an image over the page, and which one.
+    #[cfg(feature = "graphics")]
+    fn glyph_image(&self) -> Option<(ratatui::layout::Rect, char)> {
+        None
+    }
+}
+
+/// How long to wait for an event before sending a tick.
+pub const TICK_RATE: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Routes actions to the current store and swaps stores when they hand over to a new state.
+/// The command palette and the key help open over any store and take keys and clicks while they
+/// are open.
+pub struct Dispatcher {
+    store: Option<Box<dyn Store>>,
+    /// Settings of the page last switched to, which the palette builds its pages from.
+    settings: crate::words::WordReq,
+    palette: Option<crate::palette::Palette>,
+    help: bool,
+    /// Whether anything may have changed since the last draw.
+    dirty: bool,
+    /// Set by SIGINT, SIGTERM or SIGHUP, which end the loop like quitting does.
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl Dispatcher {
+    pub fn new(state: State) -> Self {
+        let mut dispatcher = Self {
+            store: None,
+            settings: crate::words::WordReq::default(),
+            palette: None,
+            help: false,
+            dirty: true,
+            stop: std::sync::Arc::default(),
+        };
+        let mut signals = vec![signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM];
+        #[cfg(unix)]
+        signals.push(signal_hook::consts::SIGHUP);
+        for signal in signals {
+            signal_hook::flag::register(signal, dispatcher.stop.clone()).unwrap();
+        }
+        dispatcher.switch(state);
+
+        dispatcher
+    }
+
+    fn switch(&mut self, state: State) {
+        if let Some(settings) = state.settings() {
+            self.settings = settings.clone();
+        }
+        self.store = state.into_store();
+        self.dirty = true;
+    }
+
+    /// Sends an action to the palette when it is open, and to the current store otherwise.
+    /// Returns false once the app has been asked to exit.
+    pub fn dispatch(&mut self, action: &Action) -> bool {
+        let input = matches!(action, Action::Key(_) | Action::Mouse(_));
+
+        if self.help && input {
+            self.help = false;
+            return true;
+        }
+
+        if let (Some(palette), true) = (&mut self.palette, input) {
+            match palette.reduce(action) {
+                crate::palette::Outcome::Stay => (),
+                crate::palette::Outcome::Close => self.palette = None,
+                crate::palette::Outcome::Go(state) => {
+                    self.palette = None;
+                    self.switch(*state);
+                }
+            }
+
+            return self.store.is_some();
+        }
+
+        if action.is(&crate::config::CONFIG.keys.help) {
+            self.help = true;
+            return true;
+        }
+        if action.is(&crate::config::CONFIG.keys.palette) {
+            let settings = self.store.as_ref().and_then(|store| store.settings());
+            self.palette = Some(crate::palette::Palette::new(
+                settings.unwrap_or_else(|| self.settings.clone()),
+            ));
+
+            return true;
+        }
+
+        if let Some(state) = self.store.as_mut().and_then(|store| store.reduce(action)) {
+            self.switch(state);
+        }
+
+        self.store.is_some()
+    }
+
+    /// The glyph image of the store, unless the palette or the key help is open over it.
+    #[cfg(feature = "graphics")]
+    fn glyph_image(&self) -> Option<(ratatui::layout::Rect, char)> {
+        match self.palette.is_some() || self.help {
+            true => None,
+            false => self.store.as_ref().and_then(|store| store.glyph_image()),
+        }
+    }
+
+    pub fn render(&self, frame: &mut ratatui::Frame) {
+        if let Some(store) = &self.store {
+            store.render(frame);
+        }
+        if let Some(palette) = &self.palette {
+            palette.render(frame);
+        }
+        if self.help {
+            crate::help::render(frame);
+        }
+    }
+
+    /// Draws and dispatches terminal events until a store exits.
+    pub fn run(mut self, terminal: &mut ratatui::DefaultTerminal) {
+        while !self.stop.load(std::sync::atomic::Ordering::Relaxed) {
+            if self.dirty {
+                terminal.draw(|frame| self.render(frame)).unwrap();
+                self.dirty = false;
+
+                #[cfg(feature = "graphics")]
+                if crate::graphics::show(self.glyph_image()) {
+                    terminal.clear().unwrap();
+                    terminal.draw(|frame| self.render(frame)).unwrap();
+                    crate::graphics::show(self.glyph_image());
+                }
+            }
+
+            let tick_rate = self
+                .store
+                .as_ref()
+                .map_or(TICK_RATE, |store| store.tick_rate());
+            let action = match ratatui::crossterm::event::poll(tick_rate).unwrap() {
+                true => Action::from_event(ratatui::crossterm::event::read().unwrap()),
+                false => Some(Action::Tick),
+            };
+
+            let Some(action) = action else {
+                continue;
+            };
+
+            // Terminals reflow what is on screen when resized, so the next draw can't assume the
+            // old frame is still there and has to repaint everything.
+            if let Action::Resize = action {
+                terminal.clear().unwrap();
+                #[cfg(feature = "graphics")]
+                crate::graphics::forget();
+            }
+
+            self.dirty |= !matches!(action, Action::Tick)
+                || self.store.as_ref().is_some_and(|store| store.animating());
+            if !self.dispatch(&action) {
+                break;
+            }
+        }
+
+        #[cfg(feature = "graphics")]
+        crate::graphics::show(None);
+    }
+}
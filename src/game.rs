@@ -0,0 +1,787 @@
+pub struct Game {
+    settings: crate::words::WordReq,
+    ids: Vec<String>,
+    /// Sitelen pona glyph of every word, left empty when glyphs are turned off.
+    glyphs: Vec<Option<char>>,
+    definitions: Vec<String>,
+    typing: crate::typing::Typing,
+    /// Whether the details of the word under the caret are shown over the test.
+    details: bool,
+    /// Word whose definition has been revealed, when hints aren't always shown.
+    hinted: Option<usize>,
+    /// When a key was last pressed, which delayed hints wait on.
+    last_key: std::time::Instant,
+    /// The last character typed, lit up on the keyboard.
+    pressed: Option<char>,
+    seed: u64,
+    rng: rand::rngs::StdRng,
+    /// The personal best with the same settings, raced against.
+    ghost: Option<crate::replay::Recording>,
+    /// Where the text of a quote test is from.
+    source: Option<String>,
+    /// The words typed so far as last drawn.
+    colored: std::cell::RefCell<crate::text::Colored>,
+    /// When the test was last saved to be resumed, and the keystrokes it had then.
+    autosaved: Option<(std::time::Instant, usize)>,
+    /// Where the glyph of the word being typed was last drawn as an image beside its hint.
+    #[cfg(feature = "graphics")]
+    image: std::cell::Cell<Option<(ratatui::layout::Rect, char)>>,
+}
+
+impl Game {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let seed = crate::words::seed(&settings);
+        let ghost = ghost(&settings);
+
+        let mut game = Self {
+            typing: crate::typing::Typing::new(&settings),
+            settings,
+            ids: Vec::new(),
+            glyphs: Vec::new(),
+            definitions: Vec::new(),
+            details: false,
+            hinted: None,
+            last_key: crate::clock::now(),
+            pressed: None,
+            colored: std::cell::RefCell::default(),
+            autosaved: None,
+            #[cfg(feature = "graphics")]
+            image: std::cell::Cell::new(None),
+            seed,
+            rng: crate::words::seeded_rng(seed),
+            ghost,
+            source: None,
+        };
+
+        game.push_words();
+
+        game
+    }
+
+    /// Carries on with a test saved before sona was closed, paused until a key is pressed.
+    pub fn resume(session: crate::session::Session) -> Self {
+        Self {
+            typing: crate::typing::Typing::restore(&session.settings, &session),
+            ghost: ghost(&session.settings),
+            settings: session.settings,
+            ids: session.ids,
+            glyphs: session.glyphs,
+            definitions: session.definitions,
+            details: false,
+            hinted: None,
+            last_key: crate::clock::now(),
+            pressed: None,
+            colored: std::cell::RefCell::default(),
+            autosaved: Some((crate::clock::now(), session.keystrokes)),
+            #[cfg(feature = "graphics")]
+            image: std::cell::Cell::new(None),
+            seed: session.seed,
+            // Words added to a timed test go on from where the saved ones stopped.
+            rng: crate::words::seeded_rng(session.seed.wrapping_add(session.index as u64)),
+            source: session.source,
+        }
+    }
+
+    fn snapshot(&self) -> crate::session::Session {
+        crate::session::Session {
+            time: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            settings: self.settings.clone(),
+            ids: self.ids.clone(),
+            glyphs: self.glyphs.clone(),
+            words: self.typing.words.clone(),
+            definitions: self.definitions.clone(),
+            index: self.typing.index,
+            input: self.typing.input.clone(),
+            durations: self.typing.durations.clone(),
+            elapsed: self.typing.since_start(),
+            keystrokes: self.typing.keystrokes,
+            refused: self.typing.refused.clone(),
+            strokes: self.typing.strokes.clone(),
+            seed: self.seed,
+            source: self.source.clone(),
+        }
+    }
+
+    /// Saves the test every few seconds while it changes, and forgets it once nothing typed is
+    /// left.
+    fn autosave(&mut self) {
+        match (self.typing.input.is_empty(), self.autosaved) {
+            (true, Some(_)) => {
+                crate::session::Session::clear();
+                self.autosaved = None;
+            }
+            (false, Some((at, keystrokes)))
+                if crate::clock::elapsed(at) < crate::session::AUTOSAVE
+                    || keystrokes == self.typing.keystrokes => {}
+            (false, _) => {
+                self.snapshot().save();
+                self.autosaved = Some((crate::clock::now(), self.typing.keystrokes));
+            }
+            (true, None) => (),
+        }
+    }
+
+    /// Appends a new subset of words to the end of the test.
+    fn push_words(&mut self) {
+        let lesson = self
+            .settings
+            .lesson
+            .as_deref()
+            .and_then(crate::lessons::find);
+        let words = match lesson {
+            Some(lesson) => crate::lessons::subset(lesson, &mut self.rng),
+            // Timed challenges that run out start the same words over.
+            None if !self.settings.fixed.is_empty() => crate::words::fixed(&self.settings),
+            None => match self.settings.mode {
+                crate::words::Mode::Phrases => return self.push_phrases(),
+                crate::words::Mode::Quotes => return self.push_quote(),
+                crate::words::Mode::Numbers => return self.push_numbers(),
+                crate::words::Mode::Learn => crate::learn::subset(&self.settings, &mut self.rng),
+                crate::words::Mode::Drill => crate::drill::subset(&self.settings, &mut self.rng),
+                crate::words::Mode::Sentences => {
+                    crate::sentence::generate(&self.settings, &mut self.rng)
+                }
+                _ => crate::words::subset(&self.settings, &mut self.rng),
+            },
+        };
+
+        words
+            .iter()
+            .filter_map(|word| word.definition(&self.settings.lang).map(|def| (word, def)))
+            .for_each(|(word, def)| {
+                // Definitions mode types the first meaning of the word instead, with the word
+                // itself as the hint of every word of it.
+                let (tokens, glyph, hint) = match self.settings.mode {
+                    crate::words::Mode::Definitions => (
+                        crate::quiz::meanings(def)
+                            .first()
+                            .map(|meaning| meaning.split(' ').map(String::from).collect())
+                            .unwrap_or_default(),
+                        None,
+                        word.word.clone(),
+                    ),
+                    _ => (
+                        vec![word.word.clone()],
+                        crate::sitelen::glyph(&word.id),
+                        format!("{:?}: ", word.usage_category) + def,
+                    ),
+                };
+
+                tokens.iter().for_each(|token: &String| {
+                    self.ids.push(word.id.clone());
+                    if self.settings.sitelen_pona {
+                        self.glyphs.push(glyph);
+                    }
+                    self.typing.push_word(token);
+                    self.definitions.push(hint.clone());
+                });
+            });
+    }
+
+    /// Appends a new subset of phrases, with every word of a phrase taking the phrase as its id.
+    fn push_phrases(&mut self) {
+        crate::phrases::subset(&self.settings, &mut self.rng)
+            .iter()
+            .for_each(|phrase| {
+                phrase.phrase.split(' ').for_each(|word| {
+                    self.ids.push(phrase.phrase.clone());
+                    if self.settings.sitelen_pona {
+                        self.glyphs.push(crate::sitelen::glyph(word));
+                    }
+                    self.typing.push_word(word);
+                    self.definitions
+                        .push(format!("{}: {}", phrase.phrase, phrase.meaning));
+                });
+            });
+    }
+
+    /// Sets the test to an excerpt of a text.
+    fn push_quote(&mut self) {
+        let Some((tokens, source)) = crate::quotes::excerpt(self.settings.n, &mut self.rng) else {
+            return;
+        };
+
+        self.push_tokens(tokens.into_iter().map(|token| (token, None)));
+        self.source = Some(source.to_string());
+    }
+
+    /// Appends generated sentences with numbers, names and punctuation, with each sentence as the
+    /// hint of its words.
+    fn push_numbers(&mut self) {
+        let tokens = crate::numbers::generate(&self.settings, &mut self.rng);
+        self.push_tokens(
+            tokens
+                .iter()
+                .map(|(token, hint)| (token.as_str(), Some(hint.clone()))),
+        );
+    }
+
+    /// Appends words as they are written in running text, with the hints given. Words without one
+    /// that are in the word data get their definition, and the rest, like names, go without.
+    fn push_tokens<'a>(&mut self, tokens: impl IntoIterator<Item = (&'a str, Option<String>)>) {
+        tokens.into_iter().for_each(|(token, hint)| {
+            let word = crate::dict::lookup(&crate::quotes::word(token));
+            self.ids
+                .push(word.map_or_else(|| crate::quotes::word(token), |word| word.id.clone()));
+            if self.settings.sitelen_pona {
+                self.glyphs
+                    .push(word.and_then(|word| crate::sitelen::glyph(&word.id)));
+            }
+            self.typing.push_word(token);
+            self.definitions.push(hint.unwrap_or_else(|| {
+                word.and_then(|word| {
+                    word.definition(&self.settings.lang)
+                        .map(|def| format!("{:?}: {def}", word.usage_category))
+                })
+                .unwrap_or_default()
+            }));
+        });
+    }
+
+    /// The keyboard with the last typed key lit up and the next key to type marked.
+    fn keyboard<'a>(&self) -> Vec<ratatui::text::Line<'a>> {
+        use ratatui::style::Stylize;
+
+        let (word, char) = crate::text::caret(&self.typing.input);
+        let next = self
+            .typing
+            .words
+            .split_terminator(' ')
+            .nth(word)
+            .map(|word| {
+                crate::text::graphemes(word)
+                    .nth(char)
+                    .and_then(|grapheme| grapheme.chars().next())
+                    .unwrap_or(' ')
+            });
+        let theme = crate::theme::current();
+
+        crate::keyboard::keyboard(|key| {
+            let style = ratatui::style::Style::new().dark_gray();
+
+            match key {
+                _ if self.pressed.map(|c| c.to_ascii_lowercase()) == Some(key) => style.reversed(),
+                _ if next.map(|c| c.to_ascii_lowercase()) == Some(key) => {
+                    style.fg(theme.caret).bold().underlined()
+                }
+                _ => style,
+            }
+        })
+    }
+
+    /// The word at `index` in the test, by its id or else by how it is spelled.
+    fn detailed(&self, index: usize) -> Option<&'static crate::words::WordData> {
+        let word = self.ids.get(index).and_then(|id| crate::dict::lookup(id));
+        word.or_else(|| {
+            self.typing
+                .words
+                .split_terminator(' ')
+                .nth(index)
+                .and_then(crate::dict::lookup)
+        })
+    }
+
+    /// Draws the dictionary entry of a word in a box over the middle of the screen.
+    fn render_details(&self, frame: &mut ratatui::Frame, index: usize) {
+        let Some(word) = self.detailed(index) else {
+            return;
+        };
+
+        let [_, area, _] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Fill(1),
+            ratatui::layout::Constraint::Percentage(60),
+            ratatui::layout::Constraint::Fill(1),
+        ])
+        .areas(frame.area());
+        let [_, area, _] = ratatui::layout::Layout::horizontal([
+            ratatui::layout::Constraint::Fill(1),
+            ratatui::layout::Constraint::Percentage(70),
+            ratatui::layout::Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let block = ratatui::widgets::Block::bordered()
+            .title(format!(
+                " {}: ban or unban, any other key to go back ",
+                crate::keys::describe(&crate::config::CONFIG.keys.ban)
+            ))
+            .padding(ratatui::widgets::Padding::horizontal(1));
+
+        frame.render_widget(ratatui::widgets::Clear, area);
+        frame.render_widget(&block, area);
+        crate::dict::render(frame, block.inner(area), word, &self.settings.lang);
+    }
+
+    /// Records how each fully typed phrase went, a phrase being right only when all its words are.
+    fn record_phrases(&self) {
+        let mut phrase_errors = crate::stats::PHRASE_ERRORS.lock().unwrap();
+        let targets: Vec<&str> = self.typing.words.split_terminator(' ').collect();
+        let inputs: Vec<&str> = self.typing.input.split_terminator(' ').collect();
+
+        let mut start = 0;
+        while let Some(phrase) = self.ids.get(start) {
+            let end = start + phrase.split(' ').count();
+            if end > self.typing.index {
+                break;
+            }
+
+            let hit = (start..end).all(|index| {
+                targets.get(index) == inputs.get(index) && !self.typing.refused.contains(&index)
+            });
+            let time = self
+                .typing
+                .durations
+                .get(start..end)
+                .map_or(std::time::Duration::ZERO, |durations| {
+                    durations.iter().sum()
+                });
+            phrase_errors.record(phrase, hit);
+            phrase_errors.record_time(phrase, time, phrase.chars().count());
+
+            start = end;
+        }
+    }
+
+    fn finish(&self) -> crate::flow::State {
+        crate::session::Session::clear();
+        if self.settings.mode == crate::words::Mode::Phrases {
+            self.record_phrases();
+        }
+
+        let mut word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+        let mut schedule = crate::srs::SCHEDULE.lock().unwrap();
+        self.ids
+            .iter()
+            .zip(self.typing.words.split_terminator(' '))
+            .zip(self.typing.input.split_terminator(' '))
+            .zip(&self.typing.durations)
+            .take(self.typing.index)
+            .enumerate()
+            .filter(|_| {
+                !matches!(
+                    self.settings.mode,
+                    crate::words::Mode::Phrases | crate::words::Mode::Definitions
+                )
+            })
+            .filter(|(_, (((id, _), _), _))| {
+                !matches!(
+                    self.settings.mode,
+                    crate::words::Mode::Quotes | crate::words::Mode::Numbers
+                ) || crate::dict::lookup(id).is_some()
+            })
+            .for_each(|(index, (((id, target), input), duration))| {
+                let hit = !self.typing.missed(index, target, input);
+                word_errors.record(id, hit);
+                let quality = match hit {
+                    true => word_errors.level(id).quality(),
+                    false => 1,
+                };
+                schedule.review(id, quality);
+                word_errors.record_time(id, *duration, target.chars().count());
+            });
+
+        let mut result = self.result();
+        if self.settings.mode == crate::words::Mode::Learn {
+            result.introduced = crate::learn::COURSE.lock().unwrap().advance(&word_errors);
+        }
+
+        // Words typed wrong or at less than half the average speed are worth another go.
+        let average = result.timeline.iter().map(|(wpm, _)| wpm).sum::<f64>()
+            / result.timeline.len().max(1) as f64;
+        result.missed = self
+            .ids
+            .iter()
+            .zip(&result.timeline)
+            .filter(|(_, (wpm, error))| *error || *wpm < average / 2.0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        result.missed.dedup();
+        result.recording = Some(Box::new(crate::replay::Recording {
+            target: self.typing.words.clone(),
+            glyphs: self.glyphs.clone(),
+            strokes: self.typing.strokes.clone(),
+        }));
+
+        crate::flow::State::Results {
+            settings: self.settings.clone(),
+            result: Box::new(result),
+        }
+    }
+
+    /// Whether the definition of the word being typed is shown.
+    fn hint_shown(&self) -> bool {
+        match self.settings.hint {
+            crate::words::Hint::Always => true,
+            crate::words::Hint::Off => false,
+            _ => self.hinted == Some(self.typing.index),
+        }
+    }
+
+    /// Leaves room for the glyph of the word being typed as an image at the start of the header
+    /// line given, when there is a glyph, an image of it can be drawn and the hint has a line of
+    /// its own with space below it.
+    #[cfg(feature = "graphics")]
+    fn image(&self, area: ratatui::layout::Rect, line: usize, compact: bool) -> bool {
+        let (width, height) = crate::graphics::SIZE;
+        let glyph = self
+            .ids
+            .get(self.typing.index)
+            .and_then(|id| crate::sitelen::glyph(id))
+            .filter(|glyph| !compact && crate::graphics::available(*glyph));
+
+        match glyph {
+            Some(glyph) if line as u16 + height <= area.height && width < area.width => {
+                let rect = ratatui::layout::Rect::new(area.x, area.y + line as u16, width, height);
+                self.image.set(Some((rect, glyph)));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Progress bars of this test and the ghost, by words finished at the same time into the test.
+    fn race<'a>(&self, ghost: &crate::replay::Recording) -> Vec<ratatui::text::Line<'a>> {
+        use ratatui::style::Stylize;
+
+        const WIDTH: usize = 30;
+
+        let at = self.typing.since_start();
+        let ghost_words = ghost.input_at(at).matches(' ').count();
+        let total = match self.typing.time_limit() {
+            Some(_) => ghost
+                .input_at(ghost.length())
+                .matches(' ')
+                .count()
+                .max(self.typing.index),
+            None => self.ids.len(),
+        }
+        .max(1);
+
+        [("you  ", self.typing.index), ("ghost", ghost_words)]
+            .into_iter()
+            .map(|(name, words)| {
+                let filled = words.min(total) * WIDTH / total;
+                ratatui::text::Line::from(vec![
+                    format!("{name} ").dark_gray(),
+                    "━".repeat(filled).fg(crate::theme::current().correct),
+                    "─".repeat(WIDTH - filled).dark_gray(),
+                    format!(" {words}").dark_gray(),
+                ])
+            })
+            .collect()
+    }
+
+    fn result(&self) -> crate::results::TestResult {
+        let mut result = self.typing.result();
+        result.seed = Some(self.seed);
+        result.source = self.source.clone();
+
+        result
+    }
+}
+
+/// The replay of the best test with the same settings to race against, when the settings ask for
+/// one and a test of hand-picked words or a lesson isn't being typed.
+fn ghost(settings: &crate::words::WordReq) -> Option<crate::replay::Recording> {
+    match settings.ghost && settings.only.is_empty() && settings.lesson.is_none() {
+        true => crate::records::RECORDS
+            .lock()
+            .unwrap()
+            .bests
+            .get(&crate::records::key(settings))
+            .and_then(|best| best.replay.clone()),
+        false => None,
+    }
+}
+
+/// Terminals narrower or shorter than this get the compact layout.
+const COMPACT_WIDTH: u16 = 60;
+const COMPACT_HEIGHT: u16 = 16;
+
+impl crate::flow::Store for Game {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        if self.typing.timed_out() {
+            return Some(self.finish());
+        }
+
+        if self.settings.hint == crate::words::Hint::Delayed
+            && self.typing.now().duration_since(self.last_key).as_secs() >= self.settings.hint_delay
+        {
+            self.hinted = Some(self.typing.index);
+        }
+
+        if let crate::flow::Action::Tick = action {
+            self.autosave();
+        }
+
+        // Walking away pauses the test from the last key, so the time away isn't counted.
+        let idle = crate::config::CONFIG.idle;
+        if idle > 0 && crate::clock::elapsed(self.last_key).as_secs() >= idle {
+            self.typing.pause(self.last_key);
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+        self.last_key = crate::clock::now();
+
+        // The word looked up can be banned without closing its details.
+        if self.details && action.is(&crate::config::CONFIG.keys.ban) {
+            let (caret_word, _) = crate::text::caret(&self.typing.input);
+            if let Some(word) = self.detailed(caret_word) {
+                crate::banned::BANNED.lock().unwrap().toggle(&word.id);
+            }
+
+            return None;
+        }
+
+        // Any key resumes.
+        if self.typing.paused.is_some() || self.details {
+            self.details = false;
+            self.typing.resume();
+
+            return None;
+        }
+
+        let keys = &crate::config::CONFIG.keys;
+        if action.is(&keys.pause) || action.is(&keys.details) {
+            self.details = action.is(&keys.details);
+            self.typing.pause(crate::clock::now());
+
+            return None;
+        }
+        if action.is(&keys.quit) {
+            return Some(self.finish());
+        }
+        if action.is(&keys.settings) {
+            crate::session::Session::clear();
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&keys.restart) {
+            crate::session::Session::clear();
+            *self = Self::new(self.settings.clone());
+            return None;
+        }
+        if action.is(&keys.hint) {
+            if self.settings.hint != crate::words::Hint::Off {
+                self.hinted = Some(self.typing.index);
+            }
+
+            return None;
+        }
+        let outcome = match (action.char(), key.code) {
+            _ if action.is(&keys.delete_word) => self.typing.delete_word(),
+            (Some(c), _) => self.typing.key(c),
+            (None, ratatui::crossterm::event::KeyCode::Backspace) => self.typing.backspace(),
+            _ => crate::typing::Outcome::Ignored,
+        };
+        if matches!(
+            outcome,
+            crate::typing::Outcome::Typed
+                | crate::typing::Outcome::Refused
+                | crate::typing::Outcome::Finished
+        ) {
+            self.pressed = action.char();
+        }
+
+        #[cfg(feature = "sound")]
+        match outcome {
+            crate::typing::Outcome::Refused => crate::sound::play(crate::sound::Sound::Error),
+            crate::typing::Outcome::Typed | crate::typing::Outcome::Finished => crate::sound::play(
+                match action.char().is_some() && !self.settings.blind && self.typing.mistyped() {
+                    true => crate::sound::Sound::Error,
+                    false => crate::sound::Sound::Key,
+                },
+            ),
+            crate::typing::Outcome::Ignored => (),
+        }
+
+        match outcome {
+            crate::typing::Outcome::Finished => Some(self.finish()),
+            _ => {
+                if self.typing.time_limit().is_some() && self.typing.index + 10 > self.ids.len() {
+                    self.push_words();
+                }
+
+                None
+            }
+        }
+    }
+
+    /// The clock, the race and delayed hints all move on while no key is pressed.
+    fn animating(&self) -> bool {
+        self.typing.paused.is_none()
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        // Small terminals get everything above the text on one line and no padding, and minimal
+        // leaves out all but the text and what it is typed from.
+        let minimal = self.settings.minimal;
+        let compact =
+            minimal || frame.area().width < COMPACT_WIDTH || frame.area().height < COMPACT_HEIGHT;
+        #[cfg(feature = "graphics")]
+        self.image.set(None);
+
+        let (block, header_rows) = match compact {
+            true => (ratatui::widgets::Block::new(), 1),
+            false => (
+                ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0)),
+                10,
+            ),
+        };
+        let layout: [_; 2] = ratatui::layout::Layout::new(
+            ratatui::layout::Direction::Vertical,
+            ratatui::layout::Constraint::from_mins([header_rows, 100]),
+        )
+        .areas(crate::config::CONFIG.layout.apply(frame.area()));
+
+        let mut header = ratatui::text::Text::default();
+
+        let result = self.result();
+        let mut status = match self.settings.blind {
+            true => format!("{:.0} wpm   {} words", result.gross_wpm, self.typing.index),
+            false => format!(
+                "{:.0} wpm   {:.0}%   {} words",
+                result.net_wpm, result.accuracy, self.typing.index
+            ),
+        };
+        // Redrawn every tick, so the clock keeps moving between keys.
+        match (self.typing.time_limit(), self.typing.start) {
+            (Some(limit), _) => {
+                let remaining = limit
+                    .saturating_sub(self.typing.elapsed())
+                    .as_secs_f64()
+                    .ceil();
+                status = format!("{remaining}s   {status}");
+            }
+            (None, Some(start)) => {
+                let seconds = self.typing.now().duration_since(start).as_secs();
+                status = format!("{}:{:02}   {status}", seconds / 60, seconds % 60);
+            }
+            (None, None) => (),
+        }
+        if !minimal {
+            header.push_line(ratatui::text::Line::from(status).dark_gray());
+        }
+
+        // Nothing of the test is shown while paused, so it can't be read ahead.
+        if self.typing.paused.is_some() && !self.details {
+            let paused = "paused, press any key to resume";
+            match compact {
+                true => header.push_span(format!("   {paused}").dark_gray()),
+                false => header.push_line(ratatui::text::Line::from(paused).dark_gray()),
+            }
+            frame.render_widget(
+                ratatui::widgets::Paragraph::new(header),
+                block.inner(layout[0]),
+            );
+
+            return;
+        }
+
+        if let Some(ghost) = self.ghost.as_ref().filter(|_| !compact) {
+            self.race(ghost)
+                .into_iter()
+                .for_each(|line| header.push_line(line));
+        }
+
+        let hint = match self.definitions.get(self.typing.index) {
+            // The word is what the meaning is typed from, so it shows whatever the hints are.
+            Some(word) if self.settings.mode == crate::words::Mode::Definitions => {
+                let glyph = self
+                    .ids
+                    .get(self.typing.index)
+                    .and_then(|id| crate::sitelen::glyph(id))
+                    .filter(|_| self.settings.sitelen_pona)
+                    .map_or_else(String::new, |glyph| format!("{glyph}  "));
+                let spaced: Vec<String> = word.chars().map(String::from).collect();
+
+                if !compact {
+                    header.push_line(ratatui::text::Line::default());
+                }
+                Some(
+                    (glyph + &spaced.join(" "))
+                        .bold()
+                        .fg(crate::theme::current().hint),
+                )
+            }
+            Some(hint) if self.hint_shown() => {
+                #[cfg(feature = "graphics")]
+                let hint = match self.image(block.inner(layout[0]), header.lines.len(), compact) {
+                    true => format!(
+                        "{:width$}{hint}",
+                        "",
+                        width = crate::graphics::SIZE.0 as usize + 1
+                    ),
+                    false => hint.to_string(),
+                };
+                Some(hint.to_string().fg(crate::theme::current().hint))
+            }
+            Some(_) if self.settings.hint == crate::words::Hint::OnDemand => Some(
+                format!(
+                    "{}: show definition",
+                    crate::keys::describe(&crate::config::CONFIG.keys.hint)
+                )
+                .dark_gray(),
+            ),
+            _ => None,
+        };
+        match (hint, compact && !minimal) {
+            (Some(hint), true) => {
+                header.push_span("   ");
+                header.push_span(hint);
+            }
+            (Some(hint), false) => header.push_line(hint),
+            (None, _) => (),
+        }
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(header),
+            block.inner(layout[0]),
+        );
+
+        let mut area = block.inner(layout[1]);
+        if self.settings.keyboard && !compact {
+            let [text, keyboard] = ratatui::layout::Layout::vertical([
+                ratatui::layout::Constraint::Length(4),
+                ratatui::layout::Constraint::Length(4),
+            ])
+            .areas(area);
+            area = text;
+
+            frame.render_widget(ratatui::widgets::Paragraph::new(self.keyboard()), keyboard);
+        }
+
+        let mut colored = self.colored.borrow_mut();
+        let text = colored.color_text(
+            &self.typing.words,
+            &self.typing.input,
+            &self.glyphs,
+            self.settings.blind,
+        );
+        let (caret_word, _) = crate::text::caret(&self.typing.input);
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(crate::text::viewport(text, area.width, caret_word)),
+            area,
+        );
+
+        if self.details {
+            #[cfg(feature = "graphics")]
+            self.image.set(None);
+            self.render_details(frame, caret_word);
+        }
+    }
+
+    #[cfg(feature = "graphics")]
+    fn glyph_image(&self) -> Option<(ratatui::layout::Rect, char)> {
+        self.image.get()
+    }
+}
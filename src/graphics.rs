@@ -0,0 +1,318 @@
+/// The cells an image of a glyph takes up, which is roughly square in most fonts.
+pub const SIZE: (u16, u16) = (4, 2);
+
+/// The kitty image id glyphs are shown with, so the last one can be deleted.
+const KITTY_ID: u32 = 1;
+
+/// A sheet of sitelen pona glyphs, dark on light in square cells 16 to a row, in the order of their
+/// UCSUR codepoints from U+F1900.
+pub struct Atlas {
+    width: usize,
+    height: usize,
+    /// How much ink each pixel has, from 0 for none to 255.
+    ink: Vec<u8>,
+}
+
+impl Atlas {
+    /// Reads a binary PGM with at most 255 grays, which has a short text header and is simple to
+    /// make from any font with UCSUR glyphs.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut fields = Vec::new();
+        let mut at = 0;
+        while fields.len() < 4 {
+            match bytes.get(at)? {
+                b'#' => {
+                    while *bytes.get(at)? != b'\n' {
+                        at += 1;
+                    }
+                }
+                c if c.is_ascii_whitespace() => at += 1,
+                _ => {
+                    let start = at;
+                    while !bytes.get(at)?.is_ascii_whitespace() {
+                        at += 1;
+                    }
+                    fields.push(std::str::from_utf8(&bytes[start..at]).ok()?);
+                }
+            }
+        }
+
+        let [magic, width, height, max] = fields[..] else {
+            return None;
+        };
+        let (width, height, max) = (
+            width.parse::<usize>().ok()?,
+            height.parse::<usize>().ok()?,
+            max.parse::<usize>()
+                .ok()
+                .filter(|max| (1..256).contains(max))?,
+        );
+        // A single whitespace character parts the header from the pixels.
+        let pixels = bytes.get(at + 1..at + 1 + width * height)?;
+
+        (magic == "P5" && width >= 16).then(|| Self {
+            width,
+            height,
+            ink: pixels
+                .iter()
+                .map(|gray| 255 - (*gray as usize).min(max) * 255 / max)
+                .map(|ink| ink as u8)
+                .collect(),
+        })
+    }
+
+    pub fn load(path: &std::path::Path) -> Option<Self> {
+        Self::parse(&std::fs::read(path).ok()?)
+    }
+
+    /// The side of a glyph's cell in pixels.
+    fn cell(&self) -> usize {
+        self.width / 16
+    }
+
+    /// The ink of a glyph's cell, row by row, if the atlas has it.
+    pub fn glyph(&self, glyph: char) -> Option<Vec<u8>> {
+        let index = (glyph as u32).checked_sub(0xF1900)? as usize;
+        let cell = self.cell();
+        let (x, y) = (index % 16 * cell, index / 16 * cell);
+        if y + cell > self.height {
+            return None;
+        }
+
+        Some(
+            (y..y + cell)
+                .flat_map(|row| &self.ink[row * self.width + x..row * self.width + x + cell])
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// Simple line drawings of every glyph, for when the config doesn't name an atlas of its own.
+const BUILT_IN: &[u8] = include_bytes!("../res/sitelen-pona.pgm");
+
+/// The atlas from the config, falling back to the built-in one when there is none or it can't be
+/// read, read the first time an image is wanted.
+static ATLAS: std::sync::LazyLock<Option<Atlas>> = std::sync::LazyLock::new(|| {
+    crate::config::CONFIG
+        .glyphs
+        .atlas
+        .as_deref()
+        .and_then(Atlas::load)
+        .or_else(|| Atlas::parse(BUILT_IN))
+});
+
+/// The image on screen, where it is and which glyph it shows.
+static SHOWN: std::sync::Mutex<Option<(ratatui::layout::Rect, char)>> = std::sync::Mutex::new(None);
+
+/// How images are drawn in this terminal, if at all. Auto only trusts terminals that say they are
+/// kitty or one of the others known to have its protocol, since asking the terminal would mean
+/// reading its answer out of the keys.
+fn protocol() -> Option<crate::config::Images> {
+    use crate::config::Images;
+
+    match crate::config::CONFIG.glyphs.images {
+        Images::Off => None,
+        Images::Auto => {
+            let term = std::env::var("TERM").unwrap_or_default();
+            let program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+            let kitty = term.contains("kitty")
+                || std::env::var_os("KITTY_WINDOW_ID").is_some()
+                || ["WezTerm", "ghostty"].contains(&program.as_str());
+
+            kitty.then_some(Images::Kitty)
+        }
+        images => Some(images),
+    }
+}
+
+/// Whether the glyph can be shown as an image, so pages know to leave room for it.
+pub fn available(glyph: char) -> bool {
+    protocol().is_some()
+        && ATLAS
+            .as_ref()
+            .is_some_and(|atlas| atlas.glyph(glyph).is_some())
+}
+
+/// The color glyphs are drawn in, which is the hint color when the theme gives one and a light
+/// gray for themes that leave it to the terminal.
+fn color() -> (u8, u8, u8) {
+    match crate::theme::current().hint {
+        ratatui::style::Color::Rgb(r, g, b) => (r, g, b),
+        _ => (0xbb, 0xbb, 0xbb),
+    }
+}
+
+/// A glyph for the kitty graphics protocol, sent as RGBA in chunks and scaled by the terminal to
+/// fill its cells.
+fn kitty(ink: &[u8], side: usize) -> String {
+    use base64::Engine;
+
+    let (r, g, b) = color();
+    let rgba: Vec<u8> = ink.iter().flat_map(|alpha| [r, g, b, *alpha]).collect();
+    let data = base64::engine::general_purpose::STANDARD.encode(rgba);
+    let chunks: Vec<&[u8]> = data.as_bytes().chunks(4096).collect();
+
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let more = (index + 1 < chunks.len()) as u8;
+            let chunk = std::str::from_utf8(chunk).unwrap();
+            match index {
+                0 => format!(
+                    "\x1b_Ga=T,f=32,s={side},v={side},c={},r={},i={KITTY_ID},q=2,C=1,m={more};{chunk}\x1b\\",
+                    SIZE.0, SIZE.1
+                ),
+                _ => format!("\x1b_Gm={more};{chunk}\x1b\\"),
+            }
+        })
+        .collect()
+}
+
+/// A glyph as sixel, scaled to `size` pixels with nothing drawn where there is no ink.
+fn sixel(ink: &[u8], side: usize, size: usize) -> String {
+    let (r, g, b) = color();
+    let percent = |c: u8| c as usize * 100 / 255;
+    let inked = |x: usize, y: usize| ink[y * side / size * side + x * side / size] >= 128;
+
+    let mut out = format!(
+        "\x1bP0;1;0q\"1;1;{size};{size}#1;2;{};{};{}",
+        percent(r),
+        percent(g),
+        percent(b)
+    );
+    for band in (0..size).step_by(6) {
+        out.push_str("#1");
+        for x in 0..size {
+            let bits = (band..(band + 6).min(size))
+                .enumerate()
+                .filter(|(_, y)| inked(x, *y))
+                .fold(0, |bits, (bit, _)| bits | 1 << bit);
+            out.push((63 + bits) as u8 as char);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+
+    out
+}
+
+/// Shows the glyph as an image in the area, or takes the last one away. Returns whether what is
+/// under the last image has to be drawn again, which sixel needs since it draws into the text;
+/// the image is shown once called again after that.
+pub fn show(image: Option<(ratatui::layout::Rect, char)>) -> bool {
+    use std::io::Write;
+
+    let mut shown = SHOWN.lock().unwrap();
+    if *shown == image {
+        return false;
+    }
+    let (Some(protocol), Some(atlas)) = (protocol(), ATLAS.as_ref()) else {
+        return false;
+    };
+
+    let mut out = std::io::stdout().lock();
+    match (protocol, *shown) {
+        (crate::config::Images::Kitty, Some(_)) => {
+            let _ = write!(out, "\x1b_Ga=d,d=I,i={KITTY_ID},q=2\x1b\\");
+        }
+        (_, Some(_)) => {
+            *shown = None;
+            return true;
+        }
+        (_, None) => (),
+    }
+
+    *shown = image;
+    if let Some((area, ink)) = image.and_then(|(area, glyph)| Some((area, atlas.glyph(glyph)?))) {
+        let side = atlas.cell();
+        let encoded = match protocol {
+            crate::config::Images::Kitty => kitty(&ink, side),
+            _ => {
+                // Cells are assumed to be twice as tall as they are wide when the terminal doesn't
+                // say how big they are.
+                let (width, height) = ratatui::crossterm::terminal::window_size()
+                    .ok()
+                    .filter(|size| size.width > 0 && size.columns > 0 && size.rows > 0)
+                    .map_or((10, 20), |size| {
+                        (size.width / size.columns, size.height / size.rows)
+                    });
+                let size = (area.width * width).min(area.height * height) as usize;
+                sixel(&ink, side, size)
+            }
+        };
+
+        let _ = ratatui::crossterm::queue!(out, ratatui::crossterm::cursor::MoveTo(area.x, area.y));
+        let _ = out.write_all(encoded.as_bytes());
+    }
+    let _ = out.flush();
+
+    false
+}
+
+/// Drops the image from the screen after everything was cleared, so the next one is drawn anew.
+pub fn forget() {
+    use std::io::Write;
+
+    let mut shown = SHOWN.lock().unwrap();
+    if let (Some(crate::config::Images::Kitty), Some(_)) = (protocol(), *shown) {
+        let mut out = std::io::stdout().lock();
+        let _ = write!(out, "\x1b_Ga=d,d=I,i={KITTY_ID},q=2\x1b\\");
+        let _ = out.flush();
+    }
+    *shown = None;
+}
+
+#[cfg(test)]
+mod tests {
+    /// A 32 by 4 atlas with two 2 by 2 glyphs on its first row, the first solid and the second
+    /// empty.
+    fn atlas() -> super::Atlas {
+        let mut pixels = vec![255u8; 32 * 4];
+        pixels[0..2].fill(0);
+        pixels[32..34].fill(0);
+
+        let mut bytes = b"P5\n# glyphs\n32 4\n255\n".to_vec();
+        bytes.extend(pixels);
+        super::Atlas::parse(&bytes).unwrap()
+    }
+
+    #[test]
+    fn cuts_glyphs_from_the_atlas() {
+        let atlas = atlas();
+
+        assert_eq!(atlas.glyph('\u{F1900}').unwrap(), [255; 4]);
+        assert_eq!(atlas.glyph('\u{F1901}').unwrap(), [0; 4]);
+        // Two rows of glyphs fit in the atlas, so the third has none.
+        assert!(atlas.glyph('\u{F1910}').is_some());
+        assert!(atlas.glyph('\u{F1920}').is_none());
+        assert!(atlas.glyph('a').is_none());
+        assert!(super::Atlas::parse(b"P2\n32 4\n255\n").is_none());
+    }
+
+    #[test]
+    fn built_in_atlas_has_every_glyph() {
+        let atlas = super::Atlas::parse(super::BUILT_IN).unwrap();
+
+        let glyphs: Vec<char> = (0xF1900..0xF1A00)
+            .filter_map(char::from_u32)
+            .filter(|glyph| crate::sitelen::word(*glyph).is_some())
+            .collect();
+        assert_eq!(glyphs.len(), 141);
+        assert!(glyphs.iter().all(|glyph| atlas
+            .glyph(*glyph)
+            .is_some_and(|ink| ink.iter().any(|ink| *ink > 128))));
+    }
+
+    #[test]
+    fn encodes_sixel_bands() {
+        let atlas = atlas();
+        let sixel = super::sixel(&atlas.glyph('\u{F1900}').unwrap(), 2, 12);
+
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;12;12#1;2;"));
+        assert!(sixel.ends_with("\x1b\\"));
+        // Two bands of six rows, every pixel inked.
+        assert_eq!(sixel.matches(&"~".repeat(12)).count(), 2);
+    }
+}
@@ -0,0 +1,51 @@
+/// Draws every key from the config over the middle of the page, grouped by the pages it works
+/// on, so remapped keys show as they are bound.
+pub fn render(frame: &mut ratatui::Frame) {
+    use ratatui::style::Stylize;
+
+    let registry = crate::config::CONFIG.keys.registry();
+    let width = registry
+        .iter()
+        .map(|(bindings, _, _)| crate::keys::describe(bindings).chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut text = ratatui::text::Text::default();
+    crate::keys::Screen::ALL.iter().for_each(|screen| {
+        text.push_line(screen.title().bold());
+        registry
+            .iter()
+            .filter(|(_, _, screens)| screens.contains(screen))
+            .for_each(|(bindings, description, _)| {
+                text.push_line(ratatui::text::Line::from(vec![
+                    format!("  {:<width$}  ", crate::keys::describe(bindings)).yellow(),
+                    description.to_string().into(),
+                ]));
+            });
+        text.push_line(ratatui::text::Line::default());
+    });
+    text.push_line("pages list their own keys at the bottom".dark_gray());
+
+    let [_, area, _] = ratatui::layout::Layout::vertical([
+        ratatui::layout::Constraint::Fill(1),
+        ratatui::layout::Constraint::Length(text.height() as u16 + 2),
+        ratatui::layout::Constraint::Fill(1),
+    ])
+    .areas(frame.area());
+    let [_, area, _] = ratatui::layout::Layout::horizontal([
+        ratatui::layout::Constraint::Fill(1),
+        ratatui::layout::Constraint::Length(text.width() as u16 + 4),
+        ratatui::layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(
+        ratatui::widgets::Paragraph::new(text).block(
+            ratatui::widgets::Block::bordered()
+                .title(" keys, press any key to close ")
+                .padding(ratatui::widgets::Padding::horizontal(1)),
+        ),
+        area,
+    );
+}
@@ -0,0 +1,409 @@
+/// A finished test as it is kept in the history file.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct Entry {
+    /// Seconds since the unix epoch when the test ended.
+    pub time: u64,
+    pub settings: crate::words::WordReq,
+    pub wpm: f64,
+    pub raw_wpm: f64,
+    /// How even the speed was from word to word, in percent.
+    #[serde(default)]
+    pub consistency: Option<f64>,
+    pub accuracy: f64,
+    pub errors: usize,
+    pub words: (usize, usize),
+    pub keystrokes: usize,
+    /// Length of the test in seconds.
+    pub elapsed: f64,
+    /// Every keystroke of the test, which only the SQLite storage keeps.
+    #[cfg(feature = "sqlite")]
+    #[serde(skip)]
+    pub recording: Option<crate::replay::Recording>,
+}
+
+impl Entry {
+    pub fn new(settings: &crate::words::WordReq, result: &crate::results::TestResult) -> Self {
+        Self {
+            time: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            settings: crate::words::WordReq {
+                seed: result.seed.or(settings.seed),
+                ..settings.clone()
+            },
+            wpm: result.net_wpm,
+            raw_wpm: result.gross_wpm,
+            consistency: result.consistency,
+            accuracy: result.accuracy,
+            errors: result.errors,
+            words: result.words,
+            keystrokes: result.keystrokes,
+            elapsed: result.elapsed.as_secs_f64(),
+            #[cfg(feature = "sqlite")]
+            recording: result.recording.as_deref().cloned(),
+        }
+    }
+
+    /// When the test ended as "YYYY-MM-DD HH:MM" in UTC.
+    pub fn date(&self) -> String {
+        // Civil date from days since the epoch, after Howard Hinnant's days_from_civil inverse.
+        let days = (self.time / 86400) as i64 + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        let seconds = self.time % 86400;
+        format!(
+            "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60
+        )
+    }
+
+    /// Length of the test as set up, like "50 words" or "30s".
+    pub fn length(&self) -> String {
+        match self.settings.mode {
+            crate::words::Mode::Time => format!("{}s", self.settings.time),
+            crate::words::Mode::Zen => "-".to_string(),
+            _ => format!("{} words", self.settings.n),
+        }
+    }
+}
+
+/// Every finished test, oldest first.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct History {
+    pub tests: Vec<Entry>,
+}
+
+pub static HISTORY: std::sync::LazyLock<std::sync::Mutex<History>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(History::load()));
+
+impl History {
+    /// Loads the history from storage, starting empty if there is none yet.
+    pub fn load() -> Self {
+        crate::storage::STORAGE.load_history()
+    }
+
+    pub fn save(&self) {
+        crate::storage::STORAGE.save_history(self);
+    }
+
+    pub fn record(
+        &mut self,
+        settings: &crate::words::WordReq,
+        result: &crate::results::TestResult,
+    ) {
+        self.tests.push(Entry::new(settings, result));
+    }
+
+    /// The last `count` tests taken in `mode`, oldest first.
+    pub fn recent(&self, mode: crate::words::Mode, count: usize) -> Vec<&Entry> {
+        let mut recent: Vec<&Entry> = self
+            .tests
+            .iter()
+            .rev()
+            .filter(|entry| entry.settings.mode == mode)
+            .take(count)
+            .collect();
+        recent.reverse();
+
+        recent
+    }
+}
+
+/// Columns of the history table, which it can be sorted by.
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Date,
+    Mode,
+    Length,
+    Wpm,
+    Accuracy,
+}
+
+/// Widths of the table columns, in the order of `COLUMNS`.
+const WIDTHS: [u16; 5] = [17, 7, 10, 5, 9];
+
+const COLUMNS: [Column; 5] = [
+    Column::Date,
+    Column::Mode,
+    Column::Length,
+    Column::Wpm,
+    Column::Accuracy,
+];
+
+impl Column {
+    fn title(&self) -> &'static str {
+        match self {
+            Column::Date => "date",
+            Column::Mode => "mode",
+            Column::Length => "length",
+            Column::Wpm => "wpm",
+            Column::Accuracy => "accuracy",
+        }
+    }
+
+    fn cell(&self, entry: &Entry) -> String {
+        match self {
+            Column::Date => entry.date(),
+            Column::Mode => format!("{:?}", entry.settings.mode).to_lowercase(),
+            Column::Length => entry.length(),
+            Column::Wpm => format!("{:.0}", entry.wpm),
+            Column::Accuracy => format!("{:.1}%", entry.accuracy),
+        }
+    }
+
+    fn compare(&self, a: &Entry, b: &Entry) -> std::cmp::Ordering {
+        match self {
+            Column::Date => a.time.cmp(&b.time),
+            Column::Mode => Column::Mode.cell(a).cmp(&Column::Mode.cell(b)),
+            Column::Length => (a.settings.n, a.settings.time).cmp(&(b.settings.n, b.settings.time)),
+            Column::Wpm => a.wpm.total_cmp(&b.wpm),
+            Column::Accuracy => a.accuracy.total_cmp(&b.accuracy),
+        }
+    }
+}
+
+/// Lists past tests, sortable by any column, with a detail view of the selected test.
+pub struct Browser {
+    settings: crate::words::WordReq,
+    entries: Vec<Entry>,
+    sort: usize,
+    descending: bool,
+    selected: usize,
+    detail: bool,
+    /// The first row shown when last drawn, which clicks are counted from.
+    offset: std::cell::Cell<usize>,
+}
+
+impl Browser {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let mut browser = Self {
+            settings,
+            entries: HISTORY.lock().unwrap().tests.clone(),
+            sort: 0,
+            descending: true,
+            selected: 0,
+            detail: false,
+            offset: std::cell::Cell::new(0),
+        };
+        browser.sort();
+
+        browser
+    }
+
+    fn sort(&mut self) {
+        let column = COLUMNS[self.sort];
+        self.entries.sort_by(|a, b| match self.descending {
+            true => column.compare(b, a),
+            false => column.compare(a, b),
+        });
+        self.selected = 0;
+    }
+
+    fn render_table(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        use ratatui::style::Stylize;
+
+        let header = COLUMNS.iter().enumerate().map(|(index, column)| {
+            match (index == self.sort, self.descending) {
+                (true, true) => format!("{} v", column.title()).bold(),
+                (true, false) => format!("{} ^", column.title()).bold(),
+                (false, _) => column.title().to_string().into(),
+            }
+        });
+
+        let rows = self.entries.iter().map(|entry| {
+            ratatui::widgets::Row::new(COLUMNS.iter().map(|column| column.cell(entry)))
+        });
+
+        let table =
+            ratatui::widgets::Table::new(rows, WIDTHS.map(ratatui::layout::Constraint::Length))
+                .header(ratatui::widgets::Row::new(header))
+                .highlight_style(ratatui::style::Style::new().reversed());
+
+        let mut state = ratatui::widgets::TableState::new()
+            .with_offset(self.offset.get())
+            .with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, area, &mut state);
+        self.offset.set(state.offset());
+    }
+
+    /// Handles a click on the table, which starts below the top padding with its header. Clicking
+    /// a title sorts by that column, and clicking the selected test opens its details.
+    fn click(&mut self, click: ratatui::layout::Position) {
+        if self.detail {
+            self.detail = false;
+            return;
+        }
+
+        match click.y {
+            0 => (),
+            1 => {
+                let mut left = 1;
+                let column = WIDTHS.iter().position(|width| {
+                    left += width + 1;
+                    click.x < left
+                });
+                if let Some(column) = column {
+                    match column == self.sort {
+                        true => self.descending = !self.descending,
+                        false => self.sort = column,
+                    }
+                    self.sort();
+                }
+            }
+            y => {
+                let row = self.offset.get() + y as usize - 2;
+                if row == self.selected {
+                    self.detail = true;
+                } else if row < self.entries.len() {
+                    self.selected = row;
+                }
+            }
+        }
+    }
+
+    fn detail<'a>(entry: &Entry) -> ratatui::text::Text<'a> {
+        let settings = &entry.settings;
+        let categories: Vec<&str> = [
+            (settings.core, "core"),
+            (settings.common, "common"),
+            (settings.uncommon, "uncommon"),
+            (settings.obscure, "obscure"),
+            (settings.sandbox, "sandbox"),
+        ]
+        .into_iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, name)| name)
+        .collect();
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line(entry.date());
+        text.push_line(format!(
+            "mode: {}   {}",
+            Column::Mode.cell(entry),
+            entry.length()
+        ));
+        text.push_line(format!("wpm: {:.0}", entry.wpm));
+        text.push_line(format!("raw wpm: {:.0}", entry.raw_wpm));
+        if let Some(consistency) = entry.consistency {
+            text.push_line(format!("consistency: {consistency:.0}%"));
+        }
+        text.push_line(format!("accuracy: {:.1}%", entry.accuracy));
+        text.push_line(format!("errors: {}", entry.errors));
+        text.push_line(format!(
+            "correct words: {}/{}",
+            entry.words.0, entry.words.1
+        ));
+        text.push_line(format!("keystrokes: {}", entry.keystrokes));
+        text.push_line(format!("time: {:.1}s", entry.elapsed));
+        text.push_line(format!("words from: {}", categories.join(", ")));
+        text.push_line(format!("definitions: {}", settings.lang));
+        text.push_line(match settings.seed {
+            Some(seed) => format!("seed: {seed}"),
+            None => "seed: random".to_string(),
+        });
+
+        text
+    }
+}
+
+impl crate::flow::Store for Browser {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        if let Some(scroll) = action.scroll().filter(|_| !self.entries.is_empty()) {
+            self.selected = (self.selected as isize + scroll)
+                .clamp(0, self.entries.len() as isize - 1) as usize;
+        }
+        if let Some(click) = action.click().filter(|_| !self.entries.is_empty()) {
+            self.click(click);
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        let back = action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('q');
+
+        if self.detail {
+            if back || key.code == KeyCode::Enter {
+                self.detail = false;
+            }
+
+            return None;
+        }
+
+        if back {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        let rows = self.entries.len().max(1);
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % rows,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.sort = (self.sort + COLUMNS.len() - 1) % COLUMNS.len();
+                self.sort();
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.sort = (self.sort + 1) % COLUMNS.len();
+                self.sort();
+            }
+            KeyCode::Char('r') => {
+                self.descending = !self.descending;
+                self.sort();
+            }
+            KeyCode::Enter => self.detail = !self.entries.is_empty(),
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [body, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        let help_line = match (self.detail, self.entries.is_empty()) {
+            (true, _) => "enter/q: back",
+            (false, true) => "no tests yet   q: back",
+            (false, false) => {
+                "up/down: select   left/right: sort by   r: reverse   enter: details   q: back"
+            }
+        };
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(help_line.dark_gray()),
+            help,
+        );
+
+        match self.entries.get(self.selected) {
+            Some(entry) if self.detail => {
+                frame.render_widget(ratatui::widgets::Paragraph::new(Self::detail(entry)), body)
+            }
+            _ => self.render_table(frame, body),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/// Typing apps whose results can be brought into the history.
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Source {
+    Monkeytype,
+}
+
+/// A test in a Monkeytype results export, with the fields sona has a place for.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MonkeytypeResult {
+    wpm: f64,
+    raw_wpm: f64,
+    acc: f64,
+    #[serde(default)]
+    consistency: Option<f64>,
+    mode: String,
+    /// Length of the test, seconds for time mode and words for words mode, as text or a number.
+    #[serde(default)]
+    mode2: serde_json::Value,
+    /// Milliseconds since the unix epoch when the test was taken.
+    timestamp: u64,
+    test_duration: f64,
+    /// Correct, incorrect, extra and missed characters.
+    #[serde(default)]
+    char_stats: [usize; 4],
+}
+
+/// Either the results alone or the response of the results endpoint, which wraps them in data.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MonkeytypeExport {
+    Results(Vec<MonkeytypeResult>),
+    Response { data: Vec<MonkeytypeResult> },
+}
+
+impl MonkeytypeResult {
+    /// The test as a history entry. Modes sona doesn't have count as word tests.
+    fn entry(&self) -> crate::history::Entry {
+        let length = self
+            .mode2
+            .as_str()
+            .and_then(|length| length.parse().ok())
+            .or(self.mode2.as_u64());
+
+        let mut settings = crate::words::WordReq::default();
+        match self.mode.as_str() {
+            "time" => {
+                settings.mode = crate::words::Mode::Time;
+                settings.time = length.unwrap_or(settings.time);
+            }
+            "quote" => settings.mode = crate::words::Mode::Quotes,
+            "zen" => settings.mode = crate::words::Mode::Zen,
+            _ => settings.n = length.map_or(settings.n, |length| length as usize),
+        }
+
+        let [correct, incorrect, extra, _] = self.char_stats;
+
+        crate::history::Entry {
+            time: self.timestamp / 1000,
+            settings,
+            wpm: self.wpm,
+            raw_wpm: self.raw_wpm,
+            consistency: self.consistency,
+            accuracy: self.acc,
+            errors: incorrect + extra,
+            words: (0, 0),
+            keystrokes: correct + incorrect + extra,
+            elapsed: self.test_duration,
+            #[cfg(feature = "sqlite")]
+            recording: None,
+        }
+    }
+}
+
+/// Adds the tests in an export to the history, leaving out ones taken at the same second as a
+/// test already in it so importing twice changes nothing. Returns how many were added.
+pub fn run(source: Source, path: &std::path::Path) -> Result<usize, String> {
+    let json = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    let entries: Vec<crate::history::Entry> = match source {
+        Source::Monkeytype => {
+            let export: MonkeytypeExport = serde_json::from_str(&json)
+                .map_err(|err| format!("{}: not a Monkeytype export: {err}", path.display()))?;
+            let (MonkeytypeExport::Results(results) | MonkeytypeExport::Response { data: results }) =
+                export;
+
+            results.iter().map(MonkeytypeResult::entry).collect()
+        }
+    };
+
+    let mut history = crate::history::HISTORY.lock().unwrap();
+    let taken: std::collections::HashSet<u64> =
+        history.tests.iter().map(|entry| entry.time).collect();
+    let before = history.tests.len();
+    history.tests.extend(
+        entries
+            .into_iter()
+            .filter(|entry| !taken.contains(&entry.time)),
+    );
+    let added = history.tests.len() - before;
+
+    history.tests.sort_by_key(|entry| entry.time);
+    history.save();
+
+    Ok(added)
+}
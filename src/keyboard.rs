@@ -0,0 +1,50 @@
+/// Letter keys of a QWERTY keyboard, top row first.
+pub const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Draws the letter keys in staggered rows and a space bar under them, each key styled by `style`.
+pub fn keyboard<'a>(style: impl Fn(char) -> ratatui::style::Style) -> Vec<ratatui::text::Line<'a>> {
+    ROWS.iter()
+        .enumerate()
+        .map(|(row, keys)| {
+            let mut line = ratatui::text::Line::from(" ".repeat(row * 2));
+            keys.chars().for_each(|key| {
+                line.push_span(ratatui::text::Span::raw(format!(" {key} ")).style(style(key)));
+                line.push_span(" ");
+            });
+
+            line
+        })
+        .chain(std::iter::once(ratatui::text::Line::from(vec![
+            ratatui::text::Span::raw(" ".repeat(ROWS.len() * 2 + 4)),
+            ratatui::text::Span::raw(" ".repeat(20)).style(style(' ')),
+        ])))
+        .collect()
+}
+
+/// Colors every letter key from green to red by how often it was missed, leaving unused keys gray.
+pub fn heatmap<'a>(
+    keys: &std::collections::BTreeMap<char, (usize, usize)>,
+) -> Vec<ratatui::text::Line<'a>> {
+    let mut lines = keyboard(|key| {
+        let style = ratatui::style::Style::new();
+
+        match keys.get(&key).copied().unwrap_or_default() {
+            (_, 0) => style.fg(ratatui::style::Color::DarkGray),
+            (misses, total) => {
+                let rate = misses as f64 / total as f64;
+                let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * rate) as u8;
+
+                style
+                    .fg(ratatui::style::Color::Black)
+                    .bg(ratatui::style::Color::Rgb(
+                        mix(0x50, 0xe0),
+                        mix(0xc8, 0x40),
+                        mix(0x78, 0x40),
+                    ))
+            }
+        }
+    });
+    lines.truncate(ROWS.len());
+
+    lines
+}
@@ -0,0 +1,282 @@
+/// A key together with the modifiers that have to be held, written like "ctrl+c", "esc" or "q".
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(try_from = "String", into = "String")]
+pub struct Binding {
+    code: ratatui::crossterm::event::KeyCode,
+    modifiers: ratatui::crossterm::event::KeyModifiers,
+}
+
+impl Binding {
+    /// Whether the key event is this binding.
+    /// Shift only has to match when the binding asks for it, since it already changes the character.
+    pub fn matches(&self, key: &ratatui::crossterm::event::KeyEvent) -> bool {
+        use ratatui::crossterm::event::KeyModifiers;
+
+        let mut held = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            held |= key.modifiers & KeyModifiers::SHIFT;
+        }
+
+        let code = match (self.code, key.code) {
+            (
+                ratatui::crossterm::event::KeyCode::Char(bound),
+                ratatui::crossterm::event::KeyCode::Char(pressed),
+            ) if !held.is_empty() => bound.eq_ignore_ascii_case(&pressed),
+            (bound, pressed) => bound == pressed,
+        };
+
+        code && held == self.modifiers
+    }
+
+    /// The key event pressing this binding sends.
+    pub fn event(&self) -> ratatui::crossterm::event::KeyEvent {
+        ratatui::crossterm::event::KeyEvent::new(self.code, self.modifiers)
+    }
+}
+
+impl TryFrom<String> for Binding {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+        // "+" and "ctrl++" bind the plus key itself.
+        let (held, key) = match value.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => {
+                (rest.strip_suffix('+').unwrap_or(rest), "+")
+            }
+            _ => value.rsplit_once('+').unwrap_or(("", &value)),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in held.split('+').filter(|modifier| !modifier.is_empty()) {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier {modifier:?} in {value:?}")),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "enter" | "return" => KeyCode::Enter,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n) => KeyCode::F(n),
+                    None => return Err(format!("unknown key {key:?} in {value:?}")),
+                },
+            },
+        };
+
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl From<Binding> for String {
+    fn from(binding: Binding) -> Self {
+        use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut out = String::new();
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            if binding.modifiers.contains(modifier) {
+                out.push_str(name);
+            }
+        }
+
+        match binding.code {
+            KeyCode::Char(' ') => out.push_str("space"),
+            KeyCode::Char(c) => out.push(c),
+            KeyCode::F(n) => out.push_str(&format!("f{n}")),
+            KeyCode::Esc => out.push_str("esc"),
+            KeyCode::Tab => out.push_str("tab"),
+            KeyCode::Enter => out.push_str("enter"),
+            KeyCode::Backspace => out.push_str("backspace"),
+            KeyCode::Delete => out.push_str("delete"),
+            KeyCode::Up => out.push_str("up"),
+            KeyCode::Down => out.push_str("down"),
+            KeyCode::Left => out.push_str("left"),
+            KeyCode::Right => out.push_str("right"),
+            KeyCode::Home => out.push_str("home"),
+            KeyCode::End => out.push_str("end"),
+            KeyCode::PageUp => out.push_str("pageup"),
+            KeyCode::PageDown => out.push_str("pagedown"),
+            code => out.push_str(&format!("{code:?}").to_lowercase()),
+        }
+
+        out
+    }
+}
+
+impl std::fmt::Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&String::from(*self))
+    }
+}
+
+/// Lets a single binding be written without the surrounding list.
+fn one_or_many<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Binding>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Binding),
+        Many(Vec<Binding>),
+    }
+
+    Ok(match serde::Deserialize::deserialize(deserializer)? {
+        OneOrMany::One(binding) => vec![binding],
+        OneOrMany::Many(bindings) => bindings,
+    })
+}
+
+/// Keys that do the same thing on every page, each with any number of bindings.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Keys {
+    /// Ends the current test, or leaves sona from the other pages.
+    #[serde(deserialize_with = "one_or_many")]
+    pub quit: Vec<Binding>,
+    /// Starts a new test with the same settings.
+    #[serde(deserialize_with = "one_or_many")]
+    pub restart: Vec<Binding>,
+    /// Goes back to the settings page.
+    #[serde(deserialize_with = "one_or_many")]
+    pub settings: Vec<Binding>,
+    /// Stops the clock and hides the test until any key is pressed.
+    #[serde(deserialize_with = "one_or_many")]
+    pub pause: Vec<Binding>,
+    /// Shows the dictionary entry of the word being typed, pausing the test meanwhile.
+    #[serde(deserialize_with = "one_or_many")]
+    pub details: Vec<Binding>,
+    /// Marks the word looked up as never to be shown, or shows it again.
+    #[serde(deserialize_with = "one_or_many")]
+    pub ban: Vec<Binding>,
+    /// Deletes the word being typed.
+    #[serde(deserialize_with = "one_or_many")]
+    pub delete_word: Vec<Binding>,
+    /// Shows the definition of the word being typed when hints are not always shown.
+    #[serde(deserialize_with = "one_or_many")]
+    pub hint: Vec<Binding>,
+    /// Says the word again in dictation mode.
+    #[serde(deserialize_with = "one_or_many")]
+    pub replay: Vec<Binding>,
+    /// Opens the list of everything sona can do, from any page.
+    #[serde(deserialize_with = "one_or_many")]
+    pub palette: Vec<Binding>,
+    /// Shows these keys and where they work, from any page.
+    #[serde(deserialize_with = "one_or_many")]
+    pub help: Vec<Binding>,
+}
+
+impl Default for Keys {
+    fn default() -> Self {
+        let bind = |keys: &[&str]| {
+            keys.iter()
+                .map(|key| Binding::try_from(key.to_string()).unwrap())
+                .collect()
+        };
+
+        Self {
+            quit: bind(&["esc", "ctrl+c"]),
+            restart: bind(&["tab"]),
+            settings: bind(&["ctrl+s"]),
+            pause: bind(&["ctrl+p"]),
+            details: bind(&["ctrl+d"]),
+            ban: bind(&["ctrl+b"]),
+            // Most terminals send ctrl+backspace as ctrl+h.
+            delete_word: bind(&["ctrl+backspace", "ctrl+h", "ctrl+w", "alt+backspace"]),
+            hint: bind(&["ctrl+t"]),
+            replay: bind(&["ctrl+r"]),
+            palette: bind(&["ctrl+k"]),
+            help: bind(&["f1"]),
+        }
+    }
+}
+
+/// The pages a key works on, for the help overlay.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Everywhere,
+    Test,
+    Quiz,
+    Results,
+    Dictionary,
+}
+
+impl Screen {
+    pub const ALL: [Screen; 5] = [
+        Screen::Everywhere,
+        Screen::Test,
+        Screen::Quiz,
+        Screen::Results,
+        Screen::Dictionary,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Screen::Everywhere => "everywhere",
+            Screen::Test => "while typing a test",
+            Screen::Quiz => "in quizzes and dictation",
+            Screen::Results => "on the results page",
+            Screen::Dictionary => "in the dictionary",
+        }
+    }
+}
+
+impl Keys {
+    /// Every key with what it does and the pages it does it on.
+    pub fn registry(&self) -> [(&[Binding], &'static str, &'static [Screen]); 11] {
+        use Screen::*;
+
+        [
+            (&self.quit, "end the test, go back or quit", &[Everywhere]),
+            (&self.palette, "list every command", &[Everywhere]),
+            (&self.help, "show these keys", &[Everywhere]),
+            (&self.restart, "start a new test", &[Test, Quiz, Results]),
+            (&self.settings, "go to the settings", &[Test, Quiz, Results]),
+            (&self.pause, "pause", &[Test]),
+            (&self.details, "look up the word being typed", &[Test]),
+            (
+                &self.ban,
+                "never show the word looked up, or show it again",
+                &[Test, Dictionary],
+            ),
+            (
+                &self.delete_word,
+                "delete the word being typed",
+                &[Test, Quiz],
+            ),
+            (&self.hint, "show the definition", &[Test]),
+            (&self.replay, "hear the word again", &[Quiz]),
+        ]
+    }
+}
+
+/// Lists the bindings the way they are written in the config, for help lines.
+pub fn describe(bindings: &[Binding]) -> String {
+    bindings
+        .iter()
+        .map(Binding::to_string)
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,125 @@
+/// Words the course starts with.
+const START: usize = 10;
+/// Words unlocked at once when every unlocked word is learned.
+const STEP: usize = 3;
+/// Slowest a word can be typed and still count as learned, in seconds per character.
+const SLOWEST: f64 = 0.4;
+
+/// Every word in the order the course teaches it: core words first, and within a category the
+/// words with the most ku data, which are the ones most speakers use.
+pub fn order() -> Vec<&'static crate::words::WordData> {
+    let mut words: Vec<&crate::words::WordData> = crate::words::WORDS
+        .iter()
+        .filter(|word| !word.deprecated && word.definitions.is_some())
+        .collect();
+    words.sort_by_key(|word| {
+        let uses: u32 = word
+            .ku_data
+            .iter()
+            .flat_map(|ku| ku.values())
+            .map(|uses| *uses as u32)
+            .sum();
+
+        (
+            word.usage_category,
+            std::cmp::Reverse(uses),
+            word.id.clone(),
+        )
+    });
+
+    words
+}
+
+/// Whether a word is typed right and fast enough to move on from.
+fn learned(word_errors: &crate::stats::WordErrors, id: &str) -> bool {
+    word_errors.mastered(id)
+        && word_errors
+            .speed
+            .get(id)
+            .is_some_and(|speed| *speed <= SLOWEST)
+}
+
+/// How far into the course words are unlocked.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Course {
+    pub unlocked: usize,
+}
+
+impl Default for Course {
+    fn default() -> Self {
+        Self { unlocked: START }
+    }
+}
+
+pub static COURSE: std::sync::LazyLock<std::sync::Mutex<Course>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Course::load()));
+
+impl Course {
+    /// Loads the course from disk, starting from the beginning if there is none yet.
+    pub fn load() -> Self {
+        crate::storage::load("course.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("course.toml", self);
+    }
+
+    /// The words unlocked so far.
+    pub fn words(&self) -> Vec<&'static crate::words::WordData> {
+        order().into_iter().take(self.unlocked).collect()
+    }
+
+    /// Unlocks the next words for as long as every unlocked word is learned, returning the new
+    /// words.
+    pub fn advance(&mut self, word_errors: &crate::stats::WordErrors) -> Vec<String> {
+        let order = order();
+        let before = self.unlocked;
+
+        while self.unlocked < order.len()
+            && order
+                .iter()
+                .take(self.unlocked)
+                .all(|word| learned(word_errors, &word.id))
+        {
+            self.unlocked = (self.unlocked + STEP).min(order.len());
+        }
+
+        order
+            .iter()
+            .take(self.unlocked)
+            .skip(before)
+            .map(|word| word.word.clone())
+            .collect()
+    }
+}
+
+/// `settings.n` of the unlocked words, drawn again as often as needed since there are few of
+/// them, and favouring the ones not yet learned.
+pub fn subset(
+    settings: &crate::words::WordReq,
+    rng: &mut impl rand::Rng,
+) -> Vec<&'static crate::words::WordData> {
+    use rand::seq::SliceRandom;
+
+    let words = COURSE.lock().unwrap().words();
+    let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+    let banned = crate::banned::BANNED.lock().unwrap();
+    let n = match settings.only.is_empty() {
+        true => settings.n,
+        false => settings.only.len(),
+    };
+    let words: Vec<&crate::words::WordData> = words
+        .into_iter()
+        .filter(|word| settings.only.is_empty() || settings.only.contains(&word.id))
+        .filter(|word| !banned.contains(&word.id))
+        .collect();
+
+    (0..n)
+        .filter_map(|_| {
+            words
+                .choose_weighted(&mut *rng, |word| word_errors.weight(&word.id))
+                .ok()
+                .copied()
+        })
+        .collect()
+}
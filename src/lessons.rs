@@ -0,0 +1,215 @@
+/// A named set of words to practice, passed by typing them at the target speed and accuracy.
+#[derive(serde::Deserialize, Clone)]
+pub struct Lesson {
+    pub name: String,
+    /// Ids of the words of the lesson.
+    pub words: Vec<String>,
+    pub wpm: f64,
+    pub accuracy: f64,
+    /// How many times each word comes up in a test of the lesson.
+    #[serde(default = "rounds")]
+    pub rounds: usize,
+}
+
+fn rounds() -> usize {
+    3
+}
+
+#[derive(serde::Deserialize)]
+struct Lessons {
+    lessons: Vec<Lesson>,
+}
+
+/// The bundled lessons followed by those in the .toml files of the lessons directory in the
+/// config dir, in the order of the file names.
+pub static LESSONS: std::sync::LazyLock<Vec<Lesson>> = std::sync::LazyLock::new(|| {
+    let mut lessons = toml::from_str::<Lessons>(include_str!("../res/lessons.toml"))
+        .unwrap()
+        .lessons;
+
+    let mut files: Vec<std::path::PathBuf> = directories::ProjectDirs::from("", "", "sona")
+        .and_then(|dirs| std::fs::read_dir(dirs.config_dir().join("lessons")).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .collect();
+    files.sort();
+
+    lessons.extend(
+        files
+            .iter()
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .filter_map(|toml| toml::from_str::<Lessons>(&toml).ok())
+            .flat_map(|file| file.lessons),
+    );
+
+    lessons
+});
+
+/// The lesson with the given name.
+pub fn find(name: &str) -> Option<&'static Lesson> {
+    LESSONS.iter().find(|lesson| lesson.name == name)
+}
+
+/// The words of a lesson, each coming up `rounds` times in random order.
+pub fn subset(lesson: &Lesson, rng: &mut impl rand::Rng) -> Vec<&'static crate::words::WordData> {
+    use rand::seq::SliceRandom;
+
+    let mut words: Vec<&crate::words::WordData> = lesson
+        .words
+        .iter()
+        .filter_map(|id| crate::dict::lookup(id))
+        .filter(|word| !crate::banned::BANNED.lock().unwrap().contains(&word.id))
+        .flat_map(|word| std::iter::repeat_n(word, lesson.rounds))
+        .collect();
+    words.shuffle(rng);
+
+    words
+}
+
+/// Names of the lessons that have been passed.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Completed {
+    pub lessons: std::collections::BTreeSet<String>,
+}
+
+pub static COMPLETED: std::sync::LazyLock<std::sync::Mutex<Completed>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Completed::load()));
+
+impl Completed {
+    /// Loads the passed lessons from disk, starting with none.
+    pub fn load() -> Self {
+        crate::storage::load("lessons.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("lessons.toml", self);
+    }
+
+    /// Marks the lesson passed if the test met its targets, returning whether it did.
+    pub fn record(&mut self, lesson: &Lesson, result: &crate::results::TestResult) -> bool {
+        let passed = result.net_wpm >= lesson.wpm && result.accuracy >= lesson.accuracy;
+        if passed {
+            self.lessons.insert(lesson.name.clone());
+        }
+
+        passed
+    }
+}
+
+/// Lists the lessons with the ones passed checked off, and starts the one picked.
+pub struct Picker {
+    settings: crate::words::WordReq,
+    selected: usize,
+}
+
+impl Picker {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self {
+            settings,
+            selected: 0,
+        }
+    }
+}
+
+impl crate::flow::Store for Picker {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = LESSONS.len().max(1);
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+        // Lessons are listed one per line below the top padding and the heading.
+        if let Some(click) = action.click() {
+            if let Some(row) = (click.y as usize)
+                .checked_sub(3)
+                .filter(|row| *row < LESSONS.len())
+            {
+                self.selected = row;
+            }
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('q') {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % rows,
+            KeyCode::Enter => {
+                let lesson = LESSONS.get(self.selected)?;
+
+                return Some(crate::flow::State::Game {
+                    settings: crate::words::WordReq {
+                        mode: crate::words::Mode::Words,
+                        lesson: Some(lesson.name.clone()),
+                        ..self.settings.clone()
+                    },
+                });
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let completed = COMPLETED.lock().unwrap();
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line(
+            format!(
+                "{}/{} lessons passed",
+                completed.lessons.len(),
+                LESSONS.len()
+            )
+            .bold(),
+        );
+        text.push_line(ratatui::text::Line::default());
+        LESSONS.iter().enumerate().for_each(|(index, lesson)| {
+            let check = match completed.lessons.contains(&lesson.name) {
+                true => '✓',
+                false => ' ',
+            };
+            let line = ratatui::text::Line::from(format!(
+                "[{check}] {:<20}{:>4.0} wpm{:>6.0}%   {}",
+                lesson.name,
+                lesson.wpm,
+                lesson.accuracy,
+                lesson.words.join(" ")
+            ));
+
+            match index == self.selected {
+                true => text.push_line(line.reversed()),
+                false => text.push_line(line),
+            }
+        });
+        text.push_line(ratatui::text::Line::default());
+        text.push_line(
+            ratatui::text::Line::from("up/down: select   enter: start   q: back").dark_gray(),
+        );
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(text),
+            block.inner(frame.area()),
+        );
+    }
+}
@@ -1,317 +1,309 @@
-#[allow(non_camel_case_types)]
-#[derive(
-    serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
-)]
-pub enum UsageCategory {
-    core,
-    common,
-    uncommon,
-    obscure,
-    sandbox,
-}
-
-#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
-pub struct WordData {
-    pub id: String,
-    pub usage_category: UsageCategory,
-    pub word: String,
-    pub deprecated: bool,
-    pub ku_data: Option<std::collections::HashMap<String, u16>>,
-    pub pu_verbatim: Option<std::collections::HashMap<String, String>>,
-    pub commentary: Option<String>,
-    pub definitions: Option<String>,
-}
-
-static WORDS: std::sync::LazyLock<Vec<WordData>> = std::sync::LazyLock::new(|| {
-    #[derive(serde::Deserialize, serde::Serialize, Debug)]
-    struct Words {
-        words: Vec<WordData>,
-    }
-
-    #[cfg(feature = "compressed")]
-    let words = {
-        let mut toml = String::new();
-        std::io::Read::read_to_string(
-            &mut bzip2::read::BzDecoder::new(include_bytes!("../res/words.toml.bz2").as_slice()),
-            &mut toml,
-        )
-        .unwrap();
-        toml::from_str::<Words>(&toml).unwrap().words
-    };
-
-    #[cfg(not(feature = "compressed"))]
-    let mut words = {
-        toml::from_str::<Words>(include_str!("../res/words.toml"))
-            .unwrap()
-            .words
-    };
-
-    words
-});
-
-#[derive(serde::Serialize, serde::Deserialize)]
-struct WordErrors {
-    words: std::collections::HashMap<String, (i32, i32)>,
-}
-
-/// Extends iterators by first wraping its elements with Some and then chains an infinite iterator of None elements.
-fn extend<I: Clone, T: Iterator<Item = I>>(
-    iter: T,
-) -> std::iter::Chain<std::iter::Map<T, impl Fn(I) -> Option<I>>, std::iter::Repeat<Option<I>>> {
-    iter.map(Some).chain(std::iter::repeat(None))
-}
-
-/// Zips two iterators so that the resulting iterator is the length of the longest iterator.
-/// Their items are wraped with Some so that if one itterator runs out it can return None
-fn full_zip<IA: Clone, IB: Clone, A: Iterator<Item = IA>, B: Iterator<Item = IB>>(
-    a: A,
-    b: B,
-) -> impl std::iter::Iterator<Item = (Option<IA>, Option<IB>)> {
-    extend(a)
-        .zip(extend(b))
-        .take_while(|(a, b)| a.is_some() || b.is_some())
-}
-
-enum TextRenderType<'a> {
-    Correct(&'a str),
-    Incorrect { target: &'a str, input: &'a str },
-    Excess(&'a str),
-    NoInput(&'a str),
-}
+mod achievements;
+mod banned;
+mod browse;
+#[cfg(any(feature = "online", feature = "server"))]
+mod challenge;
+mod choice;
+mod cli;
+mod clock;
+mod config;
+mod confusion;
+mod daily;
+mod dashboard;
+mod dict;
+mod drill;
+mod editor;
+mod export;
+mod flow;
+mod game;
+#[cfg(feature = "graphics")]
+mod graphics;
+mod help;
+mod history;
+mod import;
+mod keyboard;
+mod keys;
+mod learn;
+mod lessons;
+mod numbers;
+mod palette;
+mod phrases;
+mod progress;
+mod quiz;
+mod quotes;
+mod records;
+mod replay;
+mod report;
+mod results;
+mod review;
+mod sentence;
+#[cfg(feature = "server")]
+mod server;
+mod session;
+mod settings;
+mod simulate;
+mod sitelen;
+#[cfg(feature = "sound")]
+mod sound;
+mod srs;
+mod stats;
+mod storage;
+mod sync;
+mod text;
+mod theme;
+mod typing;
+#[cfg(feature = "update")]
+mod update;
+mod words;
+mod wotd;
+mod zen;
 
-fn color_text<'a>(target: &str, input: &str) -> ratatui::prelude::Text<'a> {
-    use ratatui::style::Stylize;
-
-    let default = ratatui::style::Style::new();
-    let blank = default;
-    let correct = default;
-    let error = default.red().underlined();
-    let excess = default.light_yellow();
-
-    let mut colored_out = ratatui::text::Text::default();
-
-    full_zip(target.split_terminator(' '), input.split_terminator(' ')).for_each(
-        |(target, input)| {
-            match (target, input) {
-                (Some(target), None) => colored_out
-                    .push_span(ratatui::text::Span::raw("_".repeat(target.len())).style(blank)),
-                (Some(target), Some(input)) => {
-                    full_zip(target.chars(), input.chars()).for_each(|(target, input)| {
-                        match (target, input) {
-                            (Some(target), Some(input)) if target == input => colored_out
-                                .push_span(
-                                    ratatui::text::Span::raw(target.to_string()).style(correct),
-                                ),
-                            (Some(target), Some(input)) if target != input => colored_out
-                                .push_span(
-                                    ratatui::text::Span::raw(target.to_string()).style(error),
-                                ),
-                            (Some(_), None) => {
-                                colored_out.push_span(ratatui::text::Span::raw("_").style(blank))
-                            }
-                            (None, Some(input)) => colored_out.push_span(
-                                ratatui::text::Span::raw(input.to_string()).style(excess),
-                            ),
-                            _ => (),
-                        }
-                    });
+fn main() {
+    let mut cli = <cli::Cli as clap::Parser>::parse();
+
+    let mut wotd = false;
+    let mut simulation = None;
+    if let Some(command) = cli.command.take() {
+        match command {
+            #[cfg(feature = "update")]
+            cli::Command::Update => match update::run() {
+                Ok((path, count)) => println!("saved {count} words to {}", path.display()),
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            #[cfg(feature = "server")]
+            cli::Command::Serve { address } => {
+                let mut settings = config::CONFIG.words.clone();
+                cli.apply(&mut settings);
+
+                if let Err(err) = server::run(settings, &address) {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
                 }
-                _ => (),
             }
-            colored_out.push_span(ratatui::text::Span::raw(" ").style(blank));
-        },
-    );
-
-    colored_out
-}
-
-#[derive(Default, Clone, Copy)]
-struct WordReq {
-    in_use: bool,
-    deprecated: bool,
-    core: bool,
-    common: bool,
-    uncommon: bool,
-    obscure: bool,
-    sandbox: bool,
-    ku: bool,
-    pu: bool,
-    commentary: bool,
-    definitions: bool,
-    n: usize,
-}
-
-fn get_subset<'a>(settings: WordReq) -> Vec<&'a WordData> {
-    use rand::seq::SliceRandom;
-
-    let mut words: Vec<&WordData> = WORDS
-        .iter()
-        .filter(|data| settings.in_use | data.deprecated)
-        .filter(|data| settings.deprecated | !data.deprecated)
-        .filter(|data| settings.core | (data.usage_category != UsageCategory::core))
-        .filter(|data| settings.common | (data.usage_category != UsageCategory::common))
-        .filter(|data| settings.uncommon | (data.usage_category != UsageCategory::uncommon))
-        .filter(|data| settings.obscure | (data.usage_category != UsageCategory::obscure))
-        .filter(|data| settings.sandbox | (data.usage_category != UsageCategory::sandbox))
-        .filter(|data| settings.ku | data.ku_data.is_some())
-        .filter(|data| settings.pu | data.pu_verbatim.is_some())
-        .filter(|data| settings.commentary | data.commentary.is_some())
-        .filter(|data| settings.definitions | data.definitions.is_some())
-        .collect();
-
-    words.drain((settings.n)..);
-
-    words.shuffle(&mut rand::thread_rng());
-
-    words
-}
-
-enum State {
-    Game { settings: WordReq },
-    Results {},
-    Settings,
-    Exit,
-}
+            cli::Command::Anki {
+                out,
+                error_rate,
+                seconds_per_char,
+                lang,
+            } => match export::anki(&out, error_rate, seconds_per_char, &lang) {
+                Ok(cards) => println!("wrote {cards} cards to {}", out.display()),
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Dict { word, json, lang } => {
+                use_packs(&cli);
+                if let Err(err) = dict::run(&word, json, &lang) {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            }
+            cli::Command::Export { format, out } => match export::run(format, &out) {
+                Ok(paths) => paths
+                    .iter()
+                    .for_each(|path| println!("wrote {}", path.display())),
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Import { source, file } => match import::run(source, &file) {
+                Ok(added) => println!("added {added} tests to the history"),
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Sync {
+                action: cli::Sync::Export { file },
+            } => match sync::export(&file) {
+                Ok(()) => println!("wrote {}", file.display()),
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Sync {
+                action: cli::Sync::Import { file },
+            } => match sync::import(&file) {
+                Ok((tests, words)) => {
+                    println!("merged {tests} tests and the stats of {words} words")
+                }
+                Err(err) => {
+                    eprintln!("sona: {err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Validate { file } => match words::read_wordlist(&file) {
+                Ok(words) => match words.len() {
+                    1 => println!("{}: 1 word, no problems", file.display()),
+                    count => println!("{}: {count} words, no problems", file.display()),
+                },
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            },
+            cli::Command::Wotd => wotd = true,
+            cli::Command::Simulate { .. } => {
+                simulate::headless();
+                simulation = Some(command);
+            }
+        }
 
-fn get_char(event: &ratatui::crossterm::event::Event) -> Option<char> {
-    if let ratatui::crossterm::event::Event::Key(key) = event {
-        if let ratatui::crossterm::event::KeyCode::Char(c) = key.code {
-            return Some(c);
+        if !wotd && simulation.is_none() {
+            return;
         }
     }
 
-    None
-}
-
-fn render(
-    colored_out: ratatui::text::Text,
-    hint: Option<&String>,
-    terminal: &mut ratatui::DefaultTerminal,
-) {
-    terminal
-        .draw(|frame| {
-            let layout: [_; 2] = ratatui::layout::Layout::new(
-                ratatui::layout::Direction::Vertical,
-                ratatui::layout::Constraint::from_mins([10, 100]),
-            )
-            .areas(frame.area());
+    if cli.report {
+        report::print();
+        return;
+    }
 
-            let block =
-                ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+    use_packs(&cli);
 
-            if let Some(hint) = hint {
-                use ratatui::text::ToSpan;
+    let mut settings = config::CONFIG.words.clone();
+    cli.apply(&mut settings);
 
-                frame.render_widget(
-                    ratatui::widgets::Paragraph::new(hint.to_span()),
-                    block.inner(layout[0]),
-                );
+    if cli.challenge {
+        #[cfg(feature = "online")]
+        match challenge::take(&settings) {
+            Ok(challenge) => settings = challenge,
+            Err(err) => {
+                eprintln!("sona: {err}");
+                std::process::exit(1);
             }
-
-            frame.render_widget(
-                ratatui::widgets::Paragraph::new(colored_out)
-                    .wrap(ratatui::widgets::Wrap { trim: false }),
-                block.inner(layout[1]),
-            );
-        })
-        .unwrap();
-}
-
-fn handle_input(
-    index: &mut usize,
-    input: &mut String,
-    durations: &mut Vec<std::time::Duration>,
-    enter: &mut std::time::Instant,
-    exit: &mut bool,
-) {
-    let event = ratatui::crossterm::event::read().unwrap();
-
-    if input.is_empty() {
-        *enter = std::time::Instant::now();
-        durations.clear();
+        }
+        #[cfg(not(feature = "online"))]
+        {
+            eprintln!("sona: challenges need sona to be built with the online feature");
+            std::process::exit(1);
+        }
     }
 
-    match get_char(&event) {
-        Some(' ') => {
-            match durations.get_mut(*index) {
-                Some(duration) => *duration += enter.elapsed(),
-                None => durations.push(enter.elapsed()),
+    if let Some(cli::Command::Simulate {
+        script,
+        width,
+        height,
+    }) = simulation
+    {
+        let screen = std::fs::read_to_string(&script)
+            .map_err(|err| format!("couldn't read {}: {err}", script.display()))
+            .and_then(|script| {
+                simulate::run(flow::State::Game { settings }, &script, width, height)
+            });
+        match screen {
+            Ok(screen) => println!("{screen}"),
+            Err(err) => {
+                eprintln!("sona: {err}");
+                std::process::exit(1);
             }
-            *enter = std::time::Instant::now();
-
-            input.push(' ');
-            *index += 1
         }
-        Some('q') => *exit = true,
-        Some(c) => input.push(c),
-        None => {
-            if let ratatui::crossterm::event::Event::Key(ratatui::crossterm::event::KeyEvent {
-                code: ratatui::crossterm::event::KeyCode::Backspace,
-                ..
-            }) = event
-            {
-                if let Some(' ') = input.pop() {
-                    match durations.get_mut(*index) {
-                        Some(duration) => *duration += enter.elapsed(),
-                        None => durations.push(enter.elapsed()),
-                    }
-                    *enter = std::time::Instant::now();
+        return;
+    }
 
-                    *index -= 1;
-                }
-            }
+    let state = match cli.configures_test() {
+        true => flow::State::Game { settings },
+        false if session::Session::load().is_some() => flow::State::Unfinished { settings },
+        false if wotd || config::CONFIG.word_of_the_day && wotd::Seen::first_today() => {
+            flow::State::WordOfTheDay { settings }
         }
-    }
-}
+        false => flow::State::Settings { settings },
+    };
 
-fn get_word_skills() {}
+    report::Snapshots::update(&stats::WORD_ERRORS.lock().unwrap());
 
-fn main() {
     let mut terminal = ratatui::init();
-    let word_skill: std::collections::HashMap<String, (usize, usize, usize)>;
+    if config::CONFIG.mouse {
+        ratatui::crossterm::execute!(
+            std::io::stdout(),
+            ratatui::crossterm::event::EnableMouseCapture
+        )
+        .unwrap();
+    }
 
-    let mut sorted_words: Vec<WordData> = WORDS.iter().cloned().collect();
-    sorted_words.sort_unstable_by(|a, b| a.usage_category.cmp(&b.usage_category));
+    // A panic gives the terminal back and keeps what was recorded before the message is printed.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        save();
+        hook(info);
+    }));
 
-    let (ids, words, definitions) = sorted_words
-        .iter()
-        .map(|word| (&word.id, &word.word, word.usage_category, &word.definitions))
-        .filter_map(|(id, word, cat, def)| def.as_ref().map(|d| (id, word, cat, d)))
-        .fold(
-            (String::new(), String::new(), Vec::<String>::new()),
-            |(mut ai, mut aw, mut ad), (id, word, cat, def)| {
-                ai.push_str(id);
-                ai.push(' ');
-                aw.push_str(word);
-                aw.push(' ');
-                ad.push(format!("{:?}: ", cat) + def);
-                (ai, aw, ad)
-            },
-        );
+    flow::Dispatcher::new(state).run(&mut terminal);
 
-    let mut index: usize = 0;
-    let mut input = String::new();
-    let mut durations: Vec<std::time::Duration> = Vec::new();
-    let mut enter = std::time::Instant::now();
-    let mut exit = false;
+    restore();
+    save();
+}
 
-    loop {
-        let colored_out = color_text(&words, &input);
+/// Leaves the alternate screen and raw mode and stops capturing the mouse.
+fn restore() {
+    if config::CONFIG.mouse {
+        let _ = ratatui::crossterm::execute!(
+            std::io::stdout(),
+            ratatui::crossterm::event::DisableMouseCapture
+        );
+    }
+    ratatui::restore();
+}
 
-        render(colored_out, definitions.get(index), &mut terminal);
+/// Writes everything recorded while running to the data directory.
+/// Data whose lock was poisoned by a panic is skipped rather than panicking again.
+fn save() {
+    if let Ok(errors) = stats::WORD_ERRORS.lock() {
+        errors.save("word_errors.toml");
+    }
+    if let Ok(errors) = stats::PHRASE_ERRORS.lock() {
+        errors.save("phrase_errors.toml");
+    }
+    if let Ok(schedule) = srs::SCHEDULE.lock() {
+        schedule.save();
+    }
+    if let Ok(history) = history::HISTORY.lock() {
+        history.save();
+    }
+    if let Ok(unlocked) = achievements::UNLOCKED.lock() {
+        unlocked.save();
+    }
+    if let Ok(records) = records::RECORDS.lock() {
+        records.save();
+    }
+    if let Ok(course) = learn::COURSE.lock() {
+        course.save();
+    }
+    if let Ok(completed) = lessons::COMPLETED.lock() {
+        completed.save();
+    }
+    if let Ok(confusions) = confusion::CONFUSIONS.lock() {
+        confusions.save();
+    }
+    if let Ok(banned) = banned::BANNED.lock() {
+        banned.save();
+    }
+}
 
-        handle_input(
-            &mut index,
-            &mut input,
-            &mut durations,
-            &mut enter,
-            &mut exit,
-        );
+/// Lays the word packs over the built-in words: the words made in the editor, then the packs from
+/// the config, then those from the command line.
+fn use_packs(cli: &cli::Cli) {
+    let personal = words::personal_pack().filter(|path| path.exists());
+    let packs = personal
+        .iter()
+        .chain(&config::CONFIG.packs)
+        .chain(&cli.wordlist);
+    if packs.clone().next().is_none() {
+        return;
+    }
 
-        if exit {
-            break;
+    match words::read_packs(packs) {
+        Ok(packs) => words::use_wordlist(words::overlay(packs), cli.replace_words),
+        Err(err) => {
+            eprintln!("sona: {err}");
+            std::process::exit(1);
         }
     }
-
-    ratatui::restore();
 }
@@ -0,0 +1,166 @@
+/// The number words of the pu system with their values, largest first.
+const NUMBERS: [(&str, u32); 5] = [
+    ("ale", 100),
+    ("mute", 20),
+    ("luka", 5),
+    ("tu", 2),
+    ("wan", 1),
+];
+
+/// Names, which toki pona writes capitalized after the word for what they name.
+const PEOPLE: [&str; 8] = [
+    "Mali", "Sonja", "Pita", "Lisa", "Kalisa", "Nasin", "Tomi", "Ana",
+];
+/// Places, named the same way after "ma" or, for their languages, "toki".
+const PLACES: [&str; 6] = ["Kanata", "Epanja", "Nijon", "Kanse", "Tosi", "Inli"];
+
+/// Things that can be counted.
+const THINGS: [&str; 10] = [
+    "soweli", "waso", "kili", "lipu", "pipi", "kala", "kiwen", "ilo", "tomo", "jan",
+];
+
+/// A number in the pu system, where words add up from the largest down, like "mute luka tu" for
+/// 27.
+pub fn number(mut value: u32) -> Vec<&'static str> {
+    let mut words = Vec::new();
+    for (word, size) in NUMBERS {
+        while value >= size {
+            words.push(word);
+            value -= size;
+        }
+    }
+
+    words
+}
+
+/// Builds sentences with numbers, names and punctuation, which word tests never have.
+struct Builder<'r, R: rand::Rng> {
+    rng: &'r mut R,
+    /// Things that aren't banned, falling back to all of them.
+    things: Vec<&'static str>,
+    /// Each word with the sentence it is in as its hint.
+    tokens: Vec<(String, String)>,
+}
+
+impl<R: rand::Rng> Builder<'_, R> {
+    fn pick<'a>(&mut self, words: &[&'a str]) -> &'a str {
+        use rand::seq::SliceRandom;
+
+        words.choose(self.rng).unwrap()
+    }
+
+    /// A count, mostly small so the sentences stay readable, with the odd hundred.
+    fn count(&mut self) -> u32 {
+        match self.rng.gen_bool(0.1) {
+            true => self.rng.gen_range(100..=130),
+            false => self.rng.gen_range(1..=30),
+        }
+    }
+
+    /// One sentence from a handful of shapes, each with its own punctuation. It is typed with the
+    /// numbers in words, from a hint with them in digits.
+    fn sentence(&mut self) {
+        let shape = self.rng.gen_range(0..6);
+        let (first, second) = (self.count(), self.count());
+        let (person, other) = (self.pick(&PEOPLE), self.pick(&PEOPLE));
+        let place = self.pick(&PLACES);
+        let things = self.things.clone();
+        let thing = self.pick(&things);
+
+        let write = |number: fn(u32) -> String| match shape {
+            0 => format!("jan {person} li jo e {thing} {}.", number(first)),
+            1 => format!(
+                "tenpo suno {} la, jan {person} li tawa ma {place}.",
+                number(first)
+            ),
+            2 => format!(
+                "jan {person} o, sina lukin e {thing} {} anu seme?",
+                number(first)
+            ),
+            3 => format!("mi jo e {thing} {}!", number(first)),
+            4 => format!("jan {person} li toki e ni: \"mi sona e toki {place}.\""),
+            _ => format!(
+                "jan {person} li jan nanpa {}; jan {other} li jan nanpa {}.",
+                number(first),
+                number(second)
+            ),
+        };
+        let text = write(|value| number(value).join(" "));
+        let hint = write(|value| value.to_string());
+
+        self.tokens.extend(
+            text.split(' ')
+                .map(|token| (token.to_string(), hint.clone())),
+        );
+    }
+}
+
+/// Generates whole sentences until they hold at least `settings.n` words, each word paired with
+/// the sentence it is in.
+pub fn generate(
+    settings: &crate::words::WordReq,
+    rng: &mut impl rand::Rng,
+) -> Vec<(String, String)> {
+    let banned = crate::banned::BANNED.lock().unwrap();
+    let mut things: Vec<&str> = THINGS
+        .into_iter()
+        .filter(|thing| !banned.contains(thing))
+        .collect();
+    if things.is_empty() {
+        things = THINGS.to_vec();
+    }
+    drop(banned);
+
+    let mut builder = Builder {
+        rng,
+        things,
+        tokens: Vec::new(),
+    };
+    while builder.tokens.len() < settings.n {
+        builder.sentence();
+    }
+
+    builder.tokens
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn adds_up_numbers_from_the_largest_word() {
+        assert_eq!(super::number(1), ["wan"]);
+        assert_eq!(super::number(4), ["tu", "tu"]);
+        assert_eq!(super::number(27), ["mute", "luka", "tu"]);
+        assert_eq!(super::number(100), ["ale"]);
+        assert_eq!(
+            super::number(78),
+            ["mute", "mute", "mute", "luka", "luka", "luka", "tu", "wan"]
+        );
+    }
+
+    #[test]
+    fn sentences_have_names_and_punctuation() {
+        let settings = crate::words::WordReq {
+            n: 40,
+            ..Default::default()
+        };
+        let tokens = super::generate(&settings, &mut crate::words::seeded_rng(1));
+
+        assert!(tokens.len() >= 40);
+        assert!(tokens
+            .iter()
+            .any(|(token, _)| token.starts_with(char::is_uppercase)));
+        assert!(tokens
+            .iter()
+            .any(|(token, _)| token.ends_with(['.', '!', '?'])));
+        assert!(tokens.iter().all(|(token, _)| !token.is_empty()));
+
+        // Hints have the numbers in digits, and are the same for every word of a sentence.
+        assert!(tokens
+            .iter()
+            .any(|(_, hint)| hint.contains(|c: char| c.is_ascii_digit())));
+        assert!(tokens
+            .iter()
+            .all(|(token, _)| !token.contains(|c: char| c.is_ascii_digit())));
+        assert_eq!(tokens[0].1, tokens[1].1);
+    }
+}
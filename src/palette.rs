@@ -0,0 +1,289 @@
+/// What picking an entry of the palette does.
+enum Effect {
+    Go(Box<crate::flow::State>),
+    Export(crate::export::Format),
+}
+
+/// What the dispatcher should do after the palette handled an action.
+pub enum Outcome {
+    Stay,
+    Close,
+    Go(Box<crate::flow::State>),
+}
+
+/// Rows of the palette list shown at once.
+const ROWS: usize = 12;
+
+/// Lists everything sona can do from anywhere, filtered by a fuzzy search.
+pub struct Palette {
+    query: String,
+    entries: Vec<(String, Effect)>,
+    /// Indices into entries of the ones matching the query, best first.
+    matches: Vec<usize>,
+    selected: usize,
+    /// What the last export did, shown until the palette closes.
+    message: Option<String>,
+}
+
+impl Palette {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        use crate::flow::State;
+
+        let page = |name: &str, state: State| (name.to_string(), Effect::Go(Box::new(state)));
+        let mut entries = vec![page(
+            "new test",
+            State::Game {
+                settings: settings.clone(),
+            },
+        )];
+        entries.extend(crate::words::Mode::ALL.iter().map(|mode| {
+            let mut settings = settings.clone();
+            settings.mode = *mode;
+            settings.only.clear();
+            settings.lesson = None;
+
+            page(
+                &format!("new {mode:?} test").to_lowercase(),
+                State::Game { settings },
+            )
+        }));
+        let settings = || settings.clone();
+        entries.extend([
+            page(
+                "settings",
+                State::Settings {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "look up a word",
+                State::Browse {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "add a word",
+                State::Editor {
+                    settings: settings(),
+                    word: None,
+                },
+            ),
+            page(
+                "word of the day",
+                State::WordOfTheDay {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "history",
+                State::History {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "stats",
+                State::Dashboard {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "progress",
+                State::Progress {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "trophies",
+                State::Trophies {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "lessons",
+                State::Lessons {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "review queue",
+                State::Review {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "weak words",
+                State::Report {
+                    settings: settings(),
+                },
+            ),
+            page(
+                "confused letters",
+                State::Confusions {
+                    settings: settings(),
+                },
+            ),
+            (
+                "export history and stats as json".to_string(),
+                Effect::Export(crate::export::Format::Json),
+            ),
+            (
+                "export history and stats as csv".to_string(),
+                Effect::Export(crate::export::Format::Csv),
+            ),
+            page("quit", State::Exit),
+        ]);
+
+        let mut palette = Self {
+            query: String::new(),
+            entries,
+            matches: Vec::new(),
+            selected: 0,
+            message: None,
+        };
+        palette.search();
+
+        palette
+    }
+
+    fn search(&mut self) {
+        let mut scored: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (name, _))| {
+                crate::dict::fuzzy(&self.query, name).map(|score| (score, index))
+            })
+            .collect();
+        // Entries keep their order when they score the same, so an empty query lists them all.
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.selected = 0;
+    }
+
+    /// Exports to the working directory, since the palette has nowhere to ask for a path.
+    fn export(&mut self, format: crate::export::Format) {
+        let out = std::path::PathBuf::from(match format {
+            crate::export::Format::Json => "sona-export.json",
+            crate::export::Format::Csv => "sona-export.csv",
+        });
+
+        self.message = Some(match crate::export::run(format, &out) {
+            Ok(paths) => {
+                let paths: Vec<String> = paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                format!("wrote {}", paths.join(", "))
+            }
+            Err(err) => err,
+        });
+    }
+
+    pub fn reduce(&mut self, action: &crate::flow::Action) -> Outcome {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = self.matches.len().max(1);
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return Outcome::Stay;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit)
+            || action.is(&crate::config::CONFIG.keys.palette)
+        {
+            return Outcome::Close;
+        }
+
+        match key.code {
+            KeyCode::Up => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down => self.selected = (self.selected + 1) % rows,
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.search();
+            }
+            KeyCode::Enter => {
+                let Some(&index) = self.matches.get(self.selected) else {
+                    return Outcome::Stay;
+                };
+
+                // Exports keep the palette open to show how they went, pages take it over.
+                match self.entries[index].1 {
+                    Effect::Export(format) => self.export(format),
+                    Effect::Go(_) => {
+                        if let Effect::Go(state) = self.entries.swap_remove(index).1 {
+                            return Outcome::Go(state);
+                        }
+                    }
+                }
+            }
+            _ => {
+                if let Some(c) = action.char() {
+                    self.query.push(c);
+                    self.search();
+                }
+            }
+        }
+
+        Outcome::Stay
+    }
+
+    /// Draws the palette over the middle of whatever page is open.
+    pub fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let [_, area, _] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Fill(1),
+            ratatui::layout::Constraint::Length(ROWS as u16 + 5),
+            ratatui::layout::Constraint::Fill(1),
+        ])
+        .areas(frame.area());
+        let [_, area, _] = ratatui::layout::Layout::horizontal([
+            ratatui::layout::Constraint::Fill(1),
+            ratatui::layout::Constraint::Length(48),
+            ratatui::layout::Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let block = ratatui::widgets::Block::bordered()
+            .title(" commands ")
+            .padding(ratatui::widgets::Padding::horizontal(1));
+        let [search, list, status] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(2),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(area));
+
+        frame.render_widget(ratatui::widgets::Clear, area);
+        frame.render_widget(block, area);
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(format!("> {}_", self.query)),
+            search,
+        );
+
+        let items = self
+            .matches
+            .iter()
+            .map(|index| ratatui::widgets::ListItem::new(self.entries[*index].0.as_str()));
+        let mut state = ratatui::widgets::ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(
+            ratatui::widgets::List::new(items)
+                .highlight_style(ratatui::style::Style::new().reversed()),
+            list,
+            &mut state,
+        );
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(match &self.message {
+                Some(message) => message.clone().green(),
+                None => "enter: run   esc: close".dark_gray(),
+            }),
+            status,
+        );
+    }
+}
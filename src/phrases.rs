@@ -0,0 +1,64 @@
+/// A set phrase whose meaning is more than its words, like "tomo tawa" for a car.
+#[derive(serde::Deserialize, Clone)]
+pub struct Phrase {
+    pub phrase: String,
+    pub meaning: String,
+}
+
+#[derive(serde::Deserialize)]
+struct Phrases {
+    phrases: Vec<Phrase>,
+}
+
+pub static PHRASES: std::sync::LazyLock<Vec<Phrase>> = std::sync::LazyLock::new(|| {
+    toml::from_str::<Phrases>(include_str!("../res/phrases.toml"))
+        .unwrap()
+        .phrases
+});
+
+/// Picks the phrases of a test the way `words::subset` picks words, leaving out phrases not in
+/// `only` when practicing missed ones.
+pub fn subset(settings: &crate::words::WordReq, rng: &mut impl rand::Rng) -> Vec<&'static Phrase> {
+    use rand::seq::SliceRandom;
+
+    let phrases: Vec<&Phrase> = PHRASES
+        .iter()
+        .filter(|phrase| settings.only.is_empty() || settings.only.contains(&phrase.phrase))
+        .collect();
+    let phrase_errors = crate::stats::PHRASE_ERRORS.lock().unwrap();
+    let n = match settings.only.is_empty() {
+        true => settings.n,
+        false => phrases.len(),
+    };
+
+    let mut subset: Vec<&Phrase> = Vec::new();
+    while subset.len() < n {
+        let wanted = n - subset.len();
+
+        let mut round: Vec<&Phrase> = match settings.adaptive {
+            true => phrases
+                .choose_multiple_weighted(&mut *rng, wanted, |phrase| {
+                    phrase_errors.weight(&phrase.phrase)
+                })
+                .unwrap()
+                .copied()
+                .collect(),
+            false => phrases
+                .choose_multiple(&mut *rng, wanted)
+                .copied()
+                .collect(),
+        };
+        round.shuffle(rng);
+
+        if round.is_empty() {
+            break;
+        }
+        subset.append(&mut round);
+
+        if !settings.repeat {
+            break;
+        }
+    }
+
+    subset
+}
@@ -0,0 +1,361 @@
+/// Weeks shown on the practice calendar.
+const WEEKS: u64 = 26;
+
+/// Seconds practiced on every day with at least one test, by days since the unix epoch.
+pub fn practice_days(history: &crate::history::History) -> std::collections::BTreeMap<u64, f64> {
+    let mut days = std::collections::BTreeMap::new();
+    history.tests.iter().for_each(|entry| {
+        *days.entry(entry.time / 86400).or_insert(0.0) += entry.elapsed;
+    });
+
+    days
+}
+
+/// The current streak, counting today or, if there was no practice yet today, yesterday,
+/// and the longest streak ever.
+pub fn streaks(days: &std::collections::BTreeMap<u64, f64>) -> (usize, usize) {
+    let today = crate::stats::today();
+    let last = match days.contains_key(&today) {
+        true => today,
+        false => today.saturating_sub(1),
+    };
+    let current = (0..=last)
+        .rev()
+        .take_while(|day| days.contains_key(day))
+        .count();
+
+    let (longest, _, _) = days
+        .keys()
+        .fold((0, 0, None), |(longest, run, previous), day| {
+            let run = match previous {
+                Some(previous) if previous + 1 == *day => run + 1,
+                _ => 1,
+            };
+
+            (usize::max(longest, run), run, Some(*day))
+        });
+
+    (current, longest)
+}
+
+/// How far back the progress charts go.
+#[derive(Clone, Copy, PartialEq)]
+enum Range {
+    Month,
+    Quarter,
+    Year,
+    All,
+}
+
+impl Range {
+    const ALL: [Range; 4] = [Range::Month, Range::Quarter, Range::Year, Range::All];
+
+    /// Days covered, or None for every test ever taken.
+    fn days(&self) -> Option<u64> {
+        match self {
+            Range::Month => Some(30),
+            Range::Quarter => Some(91),
+            Range::Year => Some(365),
+            Range::All => None,
+        }
+    }
+
+    /// Days averaged into each point of the charts.
+    fn bucket(&self) -> u64 {
+        match self {
+            Range::Month => 1,
+            Range::Quarter | Range::Year => 7,
+            Range::All => 30,
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Range::Month => "last 30 days, by day",
+            Range::Quarter => "last 3 months, by week",
+            Range::Year => "last year, by week",
+            Range::All => "all time, by month",
+        }
+    }
+}
+
+/// Points of a chart as days after the first day charted and the value on them.
+type Points = Vec<(f64, f64)>;
+
+/// The rarest usage category a test drew words from, which is what its words are charted under.
+fn category(settings: &crate::words::WordReq) -> Option<crate::words::UsageCategory> {
+    [
+        (settings.sandbox, crate::words::UsageCategory::sandbox),
+        (settings.obscure, crate::words::UsageCategory::obscure),
+        (settings.uncommon, crate::words::UsageCategory::uncommon),
+        (settings.common, crate::words::UsageCategory::common),
+        (settings.core, crate::words::UsageCategory::core),
+    ]
+    .into_iter()
+    .find(|(on, _)| *on)
+    .map(|(_, category)| category)
+}
+
+/// Picks the option after `current` in `options`, going from the last back to None.
+fn cycle<T: Copy + PartialEq>(options: &[T], current: Option<T>) -> Option<T> {
+    match current {
+        None => options.first().copied(),
+        Some(current) => options
+            .iter()
+            .skip_while(|option| **option != current)
+            .nth(1)
+            .copied(),
+    }
+}
+
+/// Practice time, streaks and a calendar of the days practiced, over charts of speed and
+/// accuracy that can be narrowed to a mode and a usage category.
+pub struct Progress {
+    settings: crate::words::WordReq,
+    days: std::collections::BTreeMap<u64, f64>,
+    tests: Vec<crate::history::Entry>,
+    range: Range,
+    mode: Option<crate::words::Mode>,
+    category: Option<crate::words::UsageCategory>,
+}
+
+impl Progress {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let history = crate::history::HISTORY.lock().unwrap();
+
+        Self {
+            settings,
+            days: practice_days(&history),
+            tests: history.tests.clone(),
+            range: Range::Quarter,
+            mode: None,
+            category: None,
+        }
+    }
+
+    /// Average wpm and accuracy of the tests in range that match the filters, one point per
+    /// bucket of days with any tests, placed by how many days after the first day charted it is.
+    fn points(&self) -> (Points, Points, u64) {
+        let today = crate::stats::today();
+        let first = match self.range.days() {
+            Some(days) => today.saturating_sub(days - 1),
+            None => self.tests.first().map_or(today, |entry| entry.time / 86400),
+        };
+        let bucket = self.range.bucket();
+
+        let mut buckets: std::collections::BTreeMap<u64, (f64, f64, f64)> =
+            std::collections::BTreeMap::new();
+        self.tests
+            .iter()
+            .filter(|entry| entry.time / 86400 >= first)
+            .filter(|entry| entry.settings.mode.is_timed_typing())
+            .filter(|entry| self.mode.is_none_or(|mode| entry.settings.mode == mode))
+            .filter(|entry| self.category.is_none() || category(&entry.settings) == self.category)
+            .for_each(|entry| {
+                let (wpm, accuracy, tests) = buckets
+                    .entry((entry.time / 86400 - first) / bucket)
+                    .or_default();
+                *wpm += entry.wpm;
+                *accuracy += entry.accuracy;
+                *tests += 1.0;
+            });
+
+        let (wpm, accuracy) = buckets
+            .into_iter()
+            .map(|(index, (wpm, accuracy, tests))| {
+                let day = (index * bucket) as f64;
+                ((day, wpm / tests), (day, accuracy / tests))
+            })
+            .unzip();
+
+        (wpm, accuracy, today - first)
+    }
+
+    fn render_chart(
+        &self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::layout::Rect,
+        title: &str,
+        data: &[(f64, f64)],
+        days: u64,
+    ) {
+        use ratatui::style::Stylize;
+
+        let (low, high) = data
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(low, high), (_, value)| {
+                (low.min(*value), high.max(*value))
+            });
+        let (low, high) = (low.floor(), high.ceil().max(low.floor() + 1.0));
+
+        let chart = ratatui::widgets::Chart::new(vec![ratatui::widgets::Dataset::default()
+            .marker(ratatui::symbols::Marker::Braille)
+            .graph_type(ratatui::widgets::GraphType::Line)
+            .style(ratatui::style::Style::new().fg(crate::theme::current().correct))
+            .data(data)])
+        .x_axis(
+            ratatui::widgets::Axis::default()
+                .bounds([0.0, days.max(1) as f64])
+                .labels([format!("{days} days ago"), "today".to_string()]),
+        )
+        .y_axis(
+            ratatui::widgets::Axis::default()
+                .title(title.dark_gray())
+                .bounds([low, high])
+                .labels([format!("{low}"), format!("{high}")]),
+        );
+
+        frame.render_widget(chart, area);
+    }
+
+    /// One row per weekday and one column per week, each day shaded by how long was practiced.
+    fn calendar<'a>(&self) -> Vec<ratatui::text::Line<'a>> {
+        use ratatui::style::Stylize;
+
+        let today = crate::stats::today();
+        // The epoch fell on a Thursday, so this counts from Monday.
+        let weekday = (today + 3) % 7;
+        let first = (today - weekday).saturating_sub((WEEKS - 1) * 7);
+
+        ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+            .iter()
+            .enumerate()
+            .map(|(row, name)| {
+                let mut line = ratatui::text::Line::from(format!("{name} ").dark_gray());
+
+                (0..WEEKS)
+                    .map(|week| first + week * 7 + row as u64)
+                    .for_each(|day| {
+                        let cell = match (day > today, self.days.get(&day)) {
+                            (true, _) => " ".into(),
+                            (false, None) => "■".dark_gray(),
+                            (false, Some(seconds)) => {
+                                let color = match seconds / 60.0 {
+                                    minutes if minutes < 2.0 => {
+                                        ratatui::style::Color::Rgb(0x0e, 0x44, 0x29)
+                                    }
+                                    minutes if minutes < 5.0 => {
+                                        ratatui::style::Color::Rgb(0x00, 0x6d, 0x32)
+                                    }
+                                    minutes if minutes < 15.0 => {
+                                        ratatui::style::Color::Rgb(0x26, 0xa6, 0x41)
+                                    }
+                                    _ => ratatui::style::Color::Rgb(0x39, 0xd3, 0x53),
+                                };
+                                "■".fg(color)
+                            }
+                        };
+                        line.push_span(cell);
+                        line.push_span(" ");
+                    });
+
+                line
+            })
+            .collect()
+    }
+}
+
+impl crate::flow::Store for Progress {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        match action.char() {
+            _ if action.is(&crate::config::CONFIG.keys.quit) => {
+                return Some(crate::flow::State::Settings {
+                    settings: self.settings.clone(),
+                })
+            }
+            Some('q') => {
+                return Some(crate::flow::State::Settings {
+                    settings: self.settings.clone(),
+                })
+            }
+            Some('r') => {
+                self.range = cycle(&Range::ALL, Some(self.range)).unwrap_or(Range::ALL[0]);
+            }
+            Some('m') => {
+                let modes: Vec<crate::words::Mode> = crate::words::Mode::ALL
+                    .into_iter()
+                    .filter(|mode| self.tests.iter().any(|entry| entry.settings.mode == *mode))
+                    .filter(crate::words::Mode::is_timed_typing)
+                    .collect();
+                self.mode = cycle(&modes, self.mode);
+            }
+            Some('c') => {
+                let categories: Vec<crate::words::UsageCategory> = self
+                    .tests
+                    .iter()
+                    .filter_map(|entry| category(&entry.settings))
+                    .collect::<std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .collect();
+                self.category = cycle(&categories, self.category);
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let (current, longest) = streaks(&self.days);
+        let minutes = self.days.values().sum::<f64>() / 60.0;
+
+        let mut text = ratatui::text::Text::default();
+        text.push_line(format!("current streak: {current} days").bold());
+        text.push_line(format!("longest streak: {longest} days"));
+        text.push_line(format!("days practiced: {}", self.days.len()));
+        text.push_line(format!("tests: {}", self.tests.len()));
+        text.push_line(format!("time practiced: {minutes:.0} minutes"));
+        text.push_line(ratatui::text::Line::default());
+        self.calendar()
+            .into_iter()
+            .for_each(|line| text.push_line(line));
+        text.push_line(ratatui::text::Line::default());
+        text.push_line(format!(
+            "{}   {}   {}",
+            self.range.title(),
+            self.mode.map_or("all modes".to_string(), |mode| {
+                format!("{mode:?} tests").to_lowercase()
+            }),
+            self.category
+                .map_or("all words".to_string(), |category| format!(
+                    "up to {category:?}"
+                )),
+        ));
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [summary, charts, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(text.height() as u16),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        frame.render_widget(ratatui::widgets::Paragraph::new(text), summary);
+
+        let (wpm, accuracy, days) = self.points();
+        match wpm.is_empty() {
+            true => frame.render_widget(
+                ratatui::widgets::Paragraph::new("no tests in this range".dark_gray()),
+                charts,
+            ),
+            false => {
+                let [left, right] = ratatui::layout::Layout::horizontal([
+                    ratatui::layout::Constraint::Fill(1),
+                    ratatui::layout::Constraint::Fill(1),
+                ])
+                .spacing(2)
+                .areas(charts);
+                self.render_chart(frame, left, "wpm", &wpm, days);
+                self.render_chart(frame, right, "accuracy", &accuracy, days);
+            }
+        }
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new("r: range   m: mode   c: words   q: back".dark_gray()),
+            help,
+        );
+    }
+}
@@ -0,0 +1,293 @@
+/// Shows only a definition and asks for the word it belongs to, or in dictation mode says the
+/// word out loud instead. Recognize quizzes turn it round and ask for a meaning of the word.
+pub struct Quiz {
+    settings: crate::words::WordReq,
+    words: Vec<&'static crate::words::WordData>,
+    answers: Vec<String>,
+    input: String,
+    start: Option<std::time::Instant>,
+    keystrokes: usize,
+    seed: u64,
+}
+
+impl Quiz {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        let seed = crate::words::seed(&settings);
+        let dictation = settings.mode == crate::words::Mode::Dictation;
+
+        let quiz = Self {
+            words: crate::words::subset(&settings, &mut crate::words::seeded_rng(seed))
+                .into_iter()
+                .filter(|word| dictation || word.definitions.is_some())
+                .collect(),
+            settings,
+            answers: Vec::new(),
+            input: String::new(),
+            start: None,
+            keystrokes: 0,
+            seed,
+        };
+        quiz.say();
+
+        quiz
+    }
+
+    fn dictation(&self) -> bool {
+        self.settings.mode == crate::words::Mode::Dictation
+    }
+
+    /// Whether meanings are asked for instead of words. Dictation always asks for the word.
+    fn recognize(&self) -> bool {
+        self.settings.direction == crate::words::Direction::Recognize && !self.dictation()
+    }
+
+    /// The answer the quiz is after: the word itself, or when recognizing, the meaning the
+    /// answer matched or else the first one.
+    fn expected(&self, word: &crate::words::WordData, answer: &str) -> String {
+        if !self.recognize() {
+            return word.word.to_lowercase();
+        }
+
+        let meanings = meanings(word.definition(&self.settings.lang).unwrap_or_default());
+        let answer = answer.trim().to_lowercase();
+        meanings
+            .iter()
+            .find(|meaning| **meaning == answer)
+            .or(meanings.first())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Whether the answer is right, told apart the same way as in the result: trimmed and in
+    /// lowercase.
+    fn correct(&self, word: &crate::words::WordData, answer: &str) -> bool {
+        self.expected(word, answer) == answer.trim().to_lowercase()
+    }
+
+    /// Says the word being asked for, in dictation mode.
+    fn say(&self) {
+        #[cfg(feature = "sound")]
+        if let Some(audio) = self
+            .words
+            .get(self.answers.len())
+            .and_then(|word| word.audio.first())
+            .filter(|_| self.dictation())
+        {
+            crate::sound::play(crate::sound::Sound::Word(audio.clone()));
+        }
+    }
+
+    fn submit(&mut self) {
+        let word = self.words[self.answers.len()];
+        let correct = self.correct(word, &self.input);
+
+        crate::stats::WORD_ERRORS
+            .lock()
+            .unwrap()
+            .record(&word.id, correct);
+        crate::srs::SCHEDULE
+            .lock()
+            .unwrap()
+            .review(&word.id, if correct { 4 } else { 1 });
+
+        self.answers.push(std::mem::take(&mut self.input));
+        self.say();
+    }
+
+    fn finish(&self) -> crate::flow::State {
+        // Meanings can be several words long, so their spaces are kept out of the way of scoring.
+        let target: String = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                let answer = self.answers.get(index).map_or("", String::as_str);
+                self.expected(word, answer).replace(' ', "_") + " "
+            })
+            .collect();
+        let input: String = self
+            .answers
+            .iter()
+            .map(|answer| answer.trim().to_lowercase().replace(' ', "_") + " ")
+            .collect();
+        let elapsed = self.start.map(crate::clock::elapsed).unwrap_or_default();
+
+        let mut result = crate::results::TestResult::new(&target, &input, elapsed);
+        result.count_keystrokes(self.keystrokes);
+        result.seed = Some(self.seed);
+        result.missed = self
+            .words
+            .iter()
+            .zip(&self.answers)
+            .filter(|(word, answer)| !self.correct(word, answer))
+            .map(|(word, _)| word.id.clone())
+            .collect();
+
+        crate::flow::State::Results {
+            settings: self.settings.clone(),
+            result: Box::new(result),
+        }
+    }
+}
+
+impl crate::flow::Store for Quiz {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        let keys = &crate::config::CONFIG.keys;
+        if action.is(&keys.quit) {
+            return Some(self.finish());
+        }
+        if action.is(&keys.restart) {
+            return Some(crate::flow::State::Game {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&keys.settings) {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&keys.replay) {
+            self.say();
+            return None;
+        }
+
+        if self.answers.len() >= self.words.len() {
+            return Some(self.finish());
+        }
+
+        self.start.get_or_insert_with(crate::clock::now);
+
+        // Only characters that go into an answer are keystrokes, with the key that gives it in as
+        // the space after it.
+        match key.code {
+            _ if action.is(&keys.delete_word) => self.input.clear(),
+            // Meanings can have spaces in them, so only enter gives them in.
+            KeyCode::Char(' ') if self.recognize() => {
+                self.keystrokes += 1;
+                self.input.push(' ');
+            }
+            KeyCode::Char(' ') | KeyCode::Enter if !self.input.trim().is_empty() => {
+                self.keystrokes += 1;
+                self.submit();
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => (),
+            // A sitelen pona glyph gives in its word as the answer.
+            KeyCode::Char(c) if !self.recognize() && crate::sitelen::word(c).is_some() => {
+                let word = crate::sitelen::word(c).unwrap();
+                self.keystrokes += word.chars().count() + 1;
+                self.input.push_str(word);
+                self.submit();
+            }
+            KeyCode::Char(c) => {
+                self.keystrokes += 1;
+                self.input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            _ => (),
+        }
+
+        match self.answers.len() >= self.words.len() {
+            true => Some(self.finish()),
+            false => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let mut text = ratatui::text::Text::default();
+
+        text.push_line(
+            ratatui::text::Line::from(format!("{}/{}", self.answers.len(), self.words.len()))
+                .dark_gray(),
+        );
+        text.push_line(ratatui::text::Line::default());
+
+        match self.words.get(self.answers.len()) {
+            Some(_) if self.dictation() && !cfg!(feature = "sound") => text.push_line(
+                ratatui::text::Line::from(
+                    "sona was built without sound, so it can't say the words",
+                )
+                .red(),
+            ),
+            Some(_) if self.dictation() => text.push_line(
+                ratatui::text::Line::from(format!(
+                    "type the word you hear   {}: hear it again",
+                    crate::keys::describe(&crate::config::CONFIG.keys.replay)
+                ))
+                .bold(),
+            ),
+            Some(word) if self.recognize() => {
+                text.push_line(ratatui::text::Line::from(word.word.clone()).bold());
+                text.push_line(
+                    ratatui::text::Line::from("type what it means, enter: answer").dark_gray(),
+                );
+            }
+            Some(word) => text.push_line(
+                ratatui::text::Line::from(word.definition(&self.settings.lang).unwrap_or_default())
+                    .bold(),
+            ),
+            None if self.dictation() && self.answers.is_empty() => text.push_line(
+                ratatui::text::Line::from(
+                    "no words have recordings yet, run `sona update` to download them",
+                )
+                .red(),
+            ),
+            None => (),
+        }
+        text.push_line(ratatui::text::Line::default());
+        text.push_line(format!("> {}", self.input));
+        text.push_line(ratatui::text::Line::default());
+
+        if let Some((word, answer)) = self
+            .answers
+            .last()
+            .map(|answer| (self.words[self.answers.len() - 1], answer))
+        {
+            let solution = match self.recognize() {
+                true => word.definition(&self.settings.lang).unwrap_or_default(),
+                false => &word.word,
+            };
+            text.push_line(match self.correct(word, answer) {
+                true => ratatui::text::Line::from(format!("{answer} ✓")).green(),
+                false => ratatui::text::Line::from(format!("{answer} ✗ {solution}")).red(),
+            });
+        }
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(text).wrap(ratatui::widgets::Wrap { trim: false }),
+            block.inner(crate::config::CONFIG.layout.apply(frame.area())),
+        );
+    }
+}
+
+/// The separate meanings in a definition, lowercased and without notes in brackets, so
+/// "work, activity; (verb) create" gives "work", "activity" and "create".
+pub fn meanings(definition: &str) -> Vec<String> {
+    let mut plain = String::new();
+    let mut depth = 0usize;
+    definition.chars().for_each(|c| match c {
+        '(' | '[' => depth += 1,
+        ')' | ']' => depth = depth.saturating_sub(1),
+        c if depth == 0 => plain.push(c),
+        _ => (),
+    });
+
+    plain
+        .split([',', ';', '/'])
+        .map(|meaning| meaning.split_whitespace().collect::<Vec<_>>().join(" "))
+        .map(|meaning| meaning.to_lowercase())
+        .filter(|meaning| !meaning.is_empty())
+        .collect()
+}
@@ -0,0 +1,70 @@
+/// A passage of toki pona text and where it is from.
+#[derive(serde::Deserialize, Clone)]
+pub struct Quote {
+    pub text: String,
+    pub source: String,
+}
+
+#[derive(serde::Deserialize)]
+struct Quotes {
+    quotes: Vec<Quote>,
+}
+
+/// The bundled passages, along with every .txt file in the quotes directory of the config. A
+/// file's name is its source, unless its last line starts with "--" to name one.
+pub static QUOTES: std::sync::LazyLock<Vec<Quote>> = std::sync::LazyLock::new(|| {
+    let mut quotes = toml::from_str::<Quotes>(include_str!("../res/quotes.toml"))
+        .unwrap()
+        .quotes;
+
+    let files = crate::config::CONFIG
+        .quotes
+        .as_ref()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"));
+
+    quotes.extend(files.filter_map(|path| {
+        let text = std::fs::read_to_string(&path).ok()?;
+        let name = path.file_stem()?.to_string_lossy().to_string();
+
+        Some(match text.trim_end().rsplit_once('\n') {
+            Some((text, last)) if last.trim_start().starts_with("--") => Quote {
+                text: text.to_string(),
+                source: last.trim_start().trim_start_matches('-').trim().to_string(),
+            },
+            _ => Quote { text, source: name },
+        })
+    }));
+
+    quotes
+});
+
+/// The word a token of a passage stands for, without the punctuation and capitals around it.
+pub fn word(token: &str) -> String {
+    token
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// About `n` words of a random passage, starting at one of its sentences, along with its source.
+pub fn excerpt(n: usize, rng: &mut impl rand::Rng) -> Option<(Vec<&'static str>, &'static str)> {
+    use rand::seq::SliceRandom;
+
+    let quote = QUOTES.choose(rng)?;
+    let words: Vec<&str> = quote.text.split_whitespace().collect();
+
+    let starts: Vec<usize> = (0..words.len())
+        .filter(|index| *index == 0 || words[index - 1].ends_with(['.', '!', '?', ':']))
+        .filter(|index| index + n <= words.len() || *index == 0)
+        .collect();
+    let start = *starts.choose(rng).unwrap_or(&0);
+
+    Some((
+        words.into_iter().skip(start).take(n).collect(),
+        quote.source.as_str(),
+    ))
+}
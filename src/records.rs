@@ -0,0 +1,134 @@
+/// The best a test has gone with one combination of settings.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct Best {
+    pub wpm: f64,
+    pub accuracy: f64,
+    /// Seconds since the unix epoch when the wpm record was set.
+    pub time: u64,
+    /// The keystrokes of the wpm record, raced against as a ghost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay: Option<crate::replay::Recording>,
+}
+
+/// Which records a test broke, with the records it beat.
+#[derive(Clone, Copy, Default)]
+pub struct Broken {
+    pub wpm: Option<f64>,
+    pub accuracy: Option<f64>,
+}
+
+/// Personal bests keyed by mode, length and word categories, since those decide how hard a
+/// test is.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Records {
+    pub bests: std::collections::BTreeMap<String, Best>,
+}
+
+pub static RECORDS: std::sync::LazyLock<std::sync::Mutex<Records>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Records::load()));
+
+/// The settings that make tests comparable, like "time 30s core+common".
+pub fn key(settings: &crate::words::WordReq) -> String {
+    let length = match settings.mode {
+        crate::words::Mode::Time => format!("{}s", settings.time),
+        _ => format!("{} words", settings.n),
+    };
+    let categories: Vec<&str> = [
+        (settings.core, "core"),
+        (settings.common, "common"),
+        (settings.uncommon, "uncommon"),
+        (settings.obscure, "obscure"),
+        (settings.sandbox, "sandbox"),
+    ]
+    .into_iter()
+    .filter(|(on, _)| *on)
+    .map(|(_, name)| name)
+    .collect();
+
+    // Tests with stricter rules can't be compared with ones without, but older keys stay valid.
+    let strictness = match settings.strictness {
+        crate::words::Strictness::Forgiving => "",
+        crate::words::Strictness::Stop => " stop",
+    };
+    let hardcore = match settings.hardcore {
+        true => " hardcore",
+        false => "",
+    };
+
+    // Words picked by their length or letters make a different test, so the filters are kept too.
+    let mut filters = String::new();
+    match (settings.min_length, settings.max_length) {
+        (0, 0) => (),
+        (min, 0) => filters += &format!(" {min}+ letters"),
+        (min, max) => filters += &format!(" {min}-{max} letters"),
+    }
+    if !settings.with_letters.is_empty() {
+        filters += &format!(" with {}", settings.with_letters);
+    }
+    if !settings.without_letters.is_empty() {
+        filters += &format!(" without {}", settings.without_letters);
+    }
+
+    format!(
+        "{} {length} {}{strictness}{hardcore}{filters}",
+        format!("{:?}", settings.mode).to_lowercase(),
+        categories.join("+")
+    )
+}
+
+impl Records {
+    /// Loads the records from disk, starting with none.
+    pub fn load() -> Self {
+        crate::storage::load("records.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("records.toml", self);
+    }
+
+    /// Keeps the result if it beats the records for its settings. The first test with some
+    /// settings sets the records without breaking any.
+    pub fn record(
+        &mut self,
+        settings: &crate::words::WordReq,
+        result: &crate::results::TestResult,
+    ) -> Broken {
+        // Practicing missed words, lessons and zen tests say nothing about how fast one types.
+        if !settings.mode.is_timed_typing()
+            || !settings.only.is_empty()
+            || settings.lesson.is_some()
+        {
+            return Broken::default();
+        }
+
+        let time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let new = Best {
+            wpm: result.net_wpm,
+            accuracy: result.accuracy,
+            time,
+            replay: result.recording.as_deref().cloned(),
+        };
+
+        let Some(best) = self.bests.get_mut(&key(settings)) else {
+            self.bests.insert(key(settings), new);
+            return Broken::default();
+        };
+
+        let mut broken = Broken::default();
+        if new.wpm > best.wpm {
+            broken.wpm = Some(best.wpm);
+            best.wpm = new.wpm;
+            best.time = time;
+            best.replay = new.replay;
+        }
+        if new.accuracy > best.accuracy {
+            broken.accuracy = Some(best.accuracy);
+            best.accuracy = new.accuracy;
+        }
+
+        broken
+    }
+}
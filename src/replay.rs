@@ -0,0 +1,189 @@
+/// Playback speeds offered in the replay viewer.
+const SPEEDS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+
+/// How a keystroke changed the input.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Stroke {
+    Char(char),
+    Backspace,
+    DeleteWord,
+}
+
+impl Stroke {
+    /// Changes the input the way a keystroke did.
+    pub fn apply(&self, input: &mut String) {
+        match self {
+            // A glyph typed its whole word.
+            Stroke::Char(c) => match crate::sitelen::word(*c) {
+                Some(word) => input.push_str(word),
+                None => input.push(*c),
+            },
+            Stroke::Backspace => {
+                input.pop();
+            }
+            Stroke::DeleteWord => {
+                if input.ends_with(' ') {
+                    input.pop();
+                }
+                let start = input.rfind(' ').map_or(0, |space| space + 1);
+                input.truncate(start);
+            }
+        }
+    }
+}
+
+/// Every keystroke of a test with the seconds since the test started, along with the text typed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct Recording {
+    pub target: String,
+    #[serde(skip)]
+    pub glyphs: Vec<Option<char>>,
+    pub strokes: Vec<(f64, Stroke)>,
+}
+
+impl Recording {
+    /// The input as it was at the given time into the test.
+    pub fn input_at(&self, at: std::time::Duration) -> String {
+        let mut input = String::new();
+
+        self.strokes
+            .iter()
+            .take_while(|(time, _)| *time <= at.as_secs_f64())
+            .for_each(|(_, stroke)| stroke.apply(&mut input));
+
+        input
+    }
+
+    /// When the last keystroke happened.
+    pub fn length(&self) -> std::time::Duration {
+        self.strokes
+            .last()
+            .map(|(time, _)| std::time::Duration::from_secs_f64(*time))
+            .unwrap_or_default()
+    }
+}
+
+/// Plays a recording back as it was typed, at an adjustable speed.
+pub struct Playback {
+    recording: Recording,
+    position: std::time::Duration,
+    speed: usize,
+    playing: bool,
+    /// When the position was last moved on.
+    last: std::time::Instant,
+}
+
+impl Playback {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            position: std::time::Duration::ZERO,
+            speed: 2,
+            playing: true,
+            last: std::time::Instant::now(),
+        }
+    }
+
+    /// Moves the position on by the time since the last action, scaled by the speed.
+    fn advance(&mut self) {
+        let now = std::time::Instant::now();
+        if self.playing {
+            self.position += now.duration_since(self.last).mul_f64(SPEEDS[self.speed]);
+            if self.position >= self.recording.length() {
+                self.position = self.recording.length();
+                self.playing = false;
+            }
+        }
+        self.last = now;
+    }
+
+    /// Handles an action, returning false once the viewer should close.
+    pub fn reduce(&mut self, action: &crate::flow::Action) -> bool {
+        use ratatui::crossterm::event::KeyCode;
+
+        self.advance();
+
+        let crate::flow::Action::Key(key) = action else {
+            return true;
+        };
+        if action.is(&crate::config::CONFIG.keys.quit) {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return false,
+            KeyCode::Char(' ') => {
+                if self.position >= self.recording.length() {
+                    self.position = std::time::Duration::ZERO;
+                }
+                self.playing = !self.playing;
+            }
+            KeyCode::Char('r') => {
+                self.position = std::time::Duration::ZERO;
+                self.playing = true;
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => {
+                self.speed = (self.speed + 1).min(SPEEDS.len() - 1)
+            }
+            KeyCode::Char('-') | KeyCode::Left => self.speed = self.speed.saturating_sub(1),
+            _ => (),
+        }
+
+        true
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        use ratatui::style::Stylize;
+
+        let [header, text, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(2),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(area);
+
+        let state = match self.playing {
+            true => "playing",
+            false => "paused",
+        };
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                ratatui::text::Line::from(format!(
+                    "replay {state} at {}x   {:.1}s / {:.1}s",
+                    SPEEDS[self.speed],
+                    self.position.as_secs_f64(),
+                    self.recording.length().as_secs_f64()
+                ))
+                .dark_gray(),
+            ),
+            header,
+        );
+
+        let input = self.recording.input_at(self.position);
+        let colored = crate::text::color_text(
+            &self.recording.target,
+            &input,
+            &self.recording.glyphs,
+            false,
+        );
+        let (caret_word, _) = crate::text::caret(&input);
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(crate::text::viewport(
+                colored, text.width, caret_word,
+            )),
+            text,
+        );
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                "space: play/pause   +/-: speed   r: rewind   q: close".dark_gray(),
+            ),
+            help,
+        );
+    }
+}
@@ -0,0 +1,301 @@
+/// Words shown in each ranking.
+const TOP: usize = 15;
+/// Tries a word needs before its miss rate is ranked, so one slip doesn't top the list.
+const MIN_TRIES: i32 = 3;
+
+/// Word stats as they were on a day, to tell which way each word is going.
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone)]
+pub struct Snapshot {
+    /// Days since the unix epoch when the snapshot was taken.
+    pub day: u64,
+    pub words: std::collections::HashMap<String, (i32, i32)>,
+    pub speed: std::collections::HashMap<String, f64>,
+}
+
+/// Weekly snapshots of the word stats. Trends compare against `previous`, which is always at
+/// least a week old once there is one.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Snapshots {
+    pub previous: Option<Snapshot>,
+    pub current: Option<Snapshot>,
+}
+
+impl Snapshots {
+    pub fn load() -> Self {
+        crate::storage::load("snapshots.toml")
+    }
+
+    /// Takes a new snapshot of the stats once the current one is a week old.
+    pub fn update(word_errors: &crate::stats::WordErrors) {
+        let mut snapshots = Self::load();
+        let today = crate::stats::today();
+
+        if snapshots
+            .current
+            .as_ref()
+            .is_some_and(|current| today < current.day + 7)
+        {
+            return;
+        }
+
+        snapshots.previous = snapshots.current.take();
+        snapshots.current = Some(Snapshot {
+            day: today,
+            words: word_errors.words.clone(),
+            speed: word_errors.speed.clone(),
+        });
+
+        crate::storage::save("snapshots.toml", &snapshots);
+    }
+}
+
+/// Which way a word has gone since last week.
+#[derive(Clone, Copy)]
+enum Trend {
+    Better,
+    Same,
+    Worse,
+    /// Not typed before last week's snapshot.
+    New,
+}
+
+impl Trend {
+    /// Compares a value where lower is better, calling changes under 5% the same.
+    fn of(now: f64, before: Option<f64>) -> Self {
+        match before {
+            None => Trend::New,
+            Some(before) if now < before * 0.95 => Trend::Better,
+            Some(before) if now > before * 1.05 => Trend::Worse,
+            Some(_) => Trend::Same,
+        }
+    }
+
+    fn arrow(&self) -> &'static str {
+        match self {
+            Trend::Better => "↑",
+            Trend::Same => "→",
+            Trend::Worse => "↓",
+            Trend::New => "·",
+        }
+    }
+
+    fn color(&self) -> ratatui::style::Color {
+        match self {
+            Trend::Better => ratatui::style::Color::Green,
+            Trend::Same | Trend::New => ratatui::style::Color::DarkGray,
+            Trend::Worse => ratatui::style::Color::Red,
+        }
+    }
+}
+
+fn miss_rate((hits, misses): (i32, i32)) -> f64 {
+    misses as f64 / (hits + misses).max(1) as f64
+}
+
+/// One ranked word: the word, its value and how it compares with last week.
+struct Row {
+    word: String,
+    value: String,
+    trend: Trend,
+}
+
+/// The slowest words and the most missed words, ranked worst first.
+struct Report {
+    slowest: Vec<Row>,
+    missed: Vec<Row>,
+    /// When the snapshot trends compare against was taken.
+    since: Option<u64>,
+}
+
+impl Report {
+    fn new() -> Self {
+        let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+        let previous = Snapshots::load().previous;
+        let name =
+            |id: &str| crate::dict::lookup(id).map_or(id.to_string(), |word| word.word.clone());
+
+        let mut slowest: Vec<(&String, f64)> = word_errors
+            .speed
+            .iter()
+            .map(|(id, speed)| (id, *speed))
+            .collect();
+        slowest.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+
+        let mut missed: Vec<(&String, (i32, i32))> = word_errors
+            .words
+            .iter()
+            .filter(|(_, (hits, misses))| hits + misses >= MIN_TRIES && *misses > 0)
+            .map(|(id, stats)| (id, *stats))
+            .collect();
+        missed.sort_by(|a, b| {
+            miss_rate(b.1)
+                .total_cmp(&miss_rate(a.1))
+                .then(b.1 .1.cmp(&a.1 .1))
+                .then(a.0.cmp(b.0))
+        });
+
+        Self {
+            slowest: slowest
+                .into_iter()
+                .take(TOP)
+                .map(|(id, speed)| Row {
+                    word: name(id),
+                    value: format!("{:.0} ms/char", speed * 1000.0),
+                    trend: Trend::of(
+                        speed,
+                        previous
+                            .as_ref()
+                            .and_then(|previous| previous.speed.get(id).copied()),
+                    ),
+                })
+                .collect(),
+            missed: missed
+                .into_iter()
+                .take(TOP)
+                .map(|(id, stats)| Row {
+                    word: name(id),
+                    value: format!("{:.0}% of {}", miss_rate(stats) * 100.0, stats.0 + stats.1),
+                    trend: Trend::of(
+                        miss_rate(stats),
+                        previous
+                            .as_ref()
+                            .and_then(|previous| previous.words.get(id).copied())
+                            .map(miss_rate),
+                    ),
+                })
+                .collect(),
+            since: previous.map(|previous| previous.day),
+        }
+    }
+
+    /// When trends are compared from, in words.
+    fn since(&self) -> String {
+        match self.since {
+            Some(day) => format!(
+                "trends since {} days ago",
+                crate::stats::today().saturating_sub(day)
+            ),
+            None => "trends show once the stats are a week old".to_string(),
+        }
+    }
+}
+
+/// Prints the report for `sona --report`.
+pub fn print() {
+    let report = Report::new();
+
+    [
+        ("slowest words", &report.slowest),
+        ("most missed words", &report.missed),
+    ]
+    .into_iter()
+    .for_each(|(title, rows)| {
+        println!("{title}");
+        rows.iter().enumerate().for_each(|(rank, row)| {
+            println!(
+                "{:>3}. {:<12}{:>16} {}",
+                rank + 1,
+                row.word,
+                row.value,
+                row.trend.arrow()
+            )
+        });
+        if rows.is_empty() {
+            println!("     none yet");
+        }
+        println!();
+    });
+    println!("{}", report.since());
+}
+
+/// The report as two tables side by side.
+pub struct Words {
+    settings: crate::words::WordReq,
+    report: Report,
+}
+
+impl Words {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self {
+            settings,
+            report: Report::new(),
+        }
+    }
+
+    fn table<'a>(title: &'a str, rows: &[Row]) -> ratatui::widgets::Table<'a> {
+        use ratatui::style::Stylize;
+
+        let rows: Vec<ratatui::widgets::Row> = rows
+            .iter()
+            .enumerate()
+            .map(|(rank, row)| {
+                ratatui::widgets::Row::new([
+                    ratatui::text::Span::raw(format!("{:>2}", rank + 1)).dark_gray(),
+                    ratatui::text::Span::raw(row.word.clone()),
+                    ratatui::text::Span::raw(row.value.clone()),
+                    ratatui::text::Span::raw(row.trend.arrow()).fg(row.trend.color()),
+                ])
+            })
+            .collect();
+
+        ratatui::widgets::Table::new(
+            rows,
+            [
+                ratatui::layout::Constraint::Length(3),
+                ratatui::layout::Constraint::Length(12),
+                ratatui::layout::Constraint::Length(14),
+                ratatui::layout::Constraint::Length(1),
+            ],
+        )
+        .header(ratatui::widgets::Row::new([title]).bold())
+    }
+}
+
+impl crate::flow::Store for Words {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        match action.char() {
+            _ if action.is(&crate::config::CONFIG.keys.quit) => {
+                Some(crate::flow::State::Settings {
+                    settings: self.settings.clone(),
+                })
+            }
+            Some('q') => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [body, since, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+        let [slowest, missed] = ratatui::layout::Layout::horizontal([
+            ratatui::layout::Constraint::Length(34),
+            ratatui::layout::Constraint::Length(34),
+        ])
+        .spacing(4)
+        .areas(body);
+
+        frame.render_widget(Self::table("slowest words", &self.report.slowest), slowest);
+        frame.render_widget(
+            Self::table("most missed words", &self.report.missed),
+            missed,
+        );
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(self.report.since().dark_gray()),
+            since,
+        );
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new("q: back".dark_gray()),
+            help,
+        );
+    }
+}
@@ -0,0 +1,767 @@
+/// Summary of a finished test.
+#[derive(Clone, Default)]
+pub struct TestResult {
+    /// Speed counting every keystroke, including the ones that were corrected.
+    pub gross_wpm: f64,
+    /// Speed of what was left typed, less a word for every uncorrected error.
+    pub net_wpm: f64,
+    pub accuracy: f64,
+    pub errors: usize,
+    /// Words typed exactly right out of the words that were finished.
+    pub words: (usize, usize),
+    pub keystrokes: usize,
+    pub elapsed: std::time::Duration,
+    /// Misses and characters typed for every key of the target text.
+    pub keys: std::collections::BTreeMap<char, (usize, usize)>,
+    /// Speed of every finished word in wpm and whether it was typed wrong, in test order.
+    pub timeline: Vec<(f64, bool)>,
+    /// Ids of the words typed wrong or slowly, to practice again.
+    pub missed: Vec<String>,
+    /// Seed the words were picked with, to share the test.
+    pub seed: Option<u64>,
+    /// How even the speed was from word to word, in percent.
+    pub consistency: Option<f64>,
+    /// Every keystroke of the test, for tests that can be replayed.
+    pub recording: Option<Box<crate::replay::Recording>>,
+    /// Where the text of a quote test is from.
+    pub source: Option<String>,
+    /// Words the course unlocked after a learn test.
+    pub introduced: Vec<String>,
+}
+
+impl TestResult {
+    /// Scores the typed input against the target text.
+    /// Words that have been passed with a space count their missing characters as errors.
+    pub fn new(target: &str, input: &str, elapsed: std::time::Duration) -> Self {
+        let typed_words = input.split_terminator(' ').count();
+        let ended = input.ends_with(' ');
+        let mut keys = std::collections::BTreeMap::new();
+
+        let (correct, errors) =
+            crate::text::full_zip(target.split_terminator(' '), input.split_terminator(' '))
+                .enumerate()
+                .fold((0, 0), |(correct, errors), (index, (target, input))| {
+                    let passed = index + 1 < typed_words || ended && index + 1 == typed_words;
+
+                    match (target, input) {
+                        (Some(target), Some(input)) => {
+                            crate::text::full_zip(target.chars(), input.chars()).fold(
+                                (correct, errors),
+                                |(correct, errors), (target, input)| {
+                                    let counted = passed || input.is_some();
+                                    if let (Some(key), true) = (target, counted) {
+                                        let (misses, total) =
+                                            keys.entry(key.to_ascii_lowercase()).or_insert((0, 0));
+                                        *misses += (target != input) as usize;
+                                        *total += 1;
+                                    }
+
+                                    match (target, input) {
+                                        (Some(target), Some(input)) if target == input => {
+                                            (correct + 1, errors)
+                                        }
+                                        (Some(_), None) if !passed => (correct, errors),
+                                        _ => (correct, errors + 1),
+                                    }
+                                },
+                            )
+                        }
+                        (None, Some(input)) => (correct, errors + input.chars().count()),
+                        _ => (correct, errors),
+                    }
+                });
+
+        let finished = match ended {
+            true => typed_words,
+            false => typed_words.saturating_sub(1),
+        };
+        let right = target
+            .split_terminator(' ')
+            .zip(input.split_terminator(' '))
+            .take(finished)
+            .filter(|(target, input)| target == input)
+            .count();
+
+        let minutes = elapsed.as_secs_f64() / 60.0;
+        let gross_wpm = match minutes > 0.0 {
+            true => input.chars().count() as f64 / 5.0 / minutes,
+            false => 0.0,
+        };
+        let net_wpm = match minutes > 0.0 {
+            true => (gross_wpm - errors as f64 / minutes).max(0.0),
+            false => 0.0,
+        };
+        let accuracy = match correct + errors {
+            0 => 0.0,
+            total => correct as f64 / total as f64 * 100.0,
+        };
+
+        Self {
+            gross_wpm,
+            net_wpm,
+            accuracy,
+            errors,
+            words: (right, finished),
+            keystrokes: 0,
+            elapsed,
+            keys,
+            timeline: Vec::new(),
+            missed: Vec::new(),
+            seed: None,
+            consistency: None,
+            recording: None,
+            source: None,
+            introduced: Vec::new(),
+        }
+    }
+
+    /// Sets the keystrokes, the characters typed into the input, and counts raw speed from them,
+    /// so corrected mistakes still count toward it. Deleting and keys refused in stop mode aren't
+    /// keystrokes.
+    pub fn count_keystrokes(&mut self, keystrokes: usize) {
+        let minutes = self.elapsed.as_secs_f64() / 60.0;
+
+        self.keystrokes = keystrokes;
+        if minutes > 0.0 {
+            self.gross_wpm = keystrokes as f64 / 5.0 / minutes;
+        }
+    }
+
+    /// Counts keys refused in stop mode as errors, since they never made it into the input.
+    pub fn count_refused(&mut self, refused: usize) {
+        if refused == 0 {
+            return;
+        }
+
+        let correct: usize = self
+            .keys
+            .values()
+            .map(|(misses, total)| total - misses)
+            .sum();
+        self.errors += refused;
+        self.accuracy = correct as f64 / (correct + self.errors) as f64 * 100.0;
+    }
+
+    /// Sets the per word speeds and how consistent they were: 100% less the coefficient of
+    /// variation of the speeds.
+    pub fn set_timeline(&mut self, timeline: Vec<(f64, bool)>) {
+        let count = timeline.len() as f64;
+        let mean = timeline.iter().map(|(wpm, _)| wpm).sum::<f64>() / count;
+        let variance = timeline
+            .iter()
+            .map(|(wpm, _)| (wpm - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        self.consistency = match timeline.len() > 1 && mean > 0.0 {
+            true => Some((100.0 - variance.sqrt() / mean * 100.0).clamp(0.0, 100.0)),
+            false => None,
+        };
+        self.timeline = timeline;
+    }
+
+    /// Scores a test made of answers that are either right or wrong.
+    pub fn answers(
+        right: usize,
+        total: usize,
+        keystrokes: usize,
+        elapsed: std::time::Duration,
+    ) -> Self {
+        Self {
+            gross_wpm: 0.0,
+            net_wpm: 0.0,
+            accuracy: match total {
+                0 => 0.0,
+                total => right as f64 / total as f64 * 100.0,
+            },
+            errors: total - right,
+            words: (right, total),
+            keystrokes,
+            elapsed,
+            keys: std::collections::BTreeMap::new(),
+            timeline: Vec::new(),
+            missed: Vec::new(),
+            seed: None,
+            consistency: None,
+            recording: None,
+            source: None,
+            introduced: Vec::new(),
+        }
+    }
+
+    /// Scores free typing where there is nothing to compare against.
+    pub fn free(input: &str, keystrokes: usize, elapsed: std::time::Duration) -> Self {
+        let minutes = elapsed.as_secs_f64() / 60.0;
+        let gross_wpm = match minutes > 0.0 {
+            true => input.chars().count() as f64 / 5.0 / minutes,
+            false => 0.0,
+        };
+
+        Self {
+            gross_wpm,
+            net_wpm: gross_wpm,
+            accuracy: 100.0,
+            errors: 0,
+            words: (0, 0),
+            keystrokes,
+            elapsed,
+            keys: std::collections::BTreeMap::new(),
+            timeline: Vec::new(),
+            missed: Vec::new(),
+            seed: None,
+            consistency: None,
+            recording: None,
+            source: None,
+            introduced: Vec::new(),
+        }
+    }
+}
+
+pub struct Results {
+    settings: crate::words::WordReq,
+    result: TestResult,
+    /// Whether the seed has been copied to the clipboard.
+    copied: bool,
+    /// Achievements this test unlocked.
+    unlocked: Vec<&'static crate::achievements::Achievement>,
+    /// Personal bests this test beat.
+    broken: crate::records::Broken,
+    /// The test being replayed over the results, if it is.
+    playback: Option<crate::replay::Playback>,
+    /// How sending the result to the server went, once it has been sent.
+    submitted: Option<String>,
+    /// The lesson taken and whether this test passed it.
+    lesson: Option<(&'static crate::lessons::Lesson, bool)>,
+    /// Wpm and accuracy of the last tests in the same mode before this one, oldest first.
+    recent: Vec<(f64, f64)>,
+}
+
+/// Tests in the same mode this one is compared against.
+const RECENT: usize = 10;
+
+/// A line of block characters as tall as each value is relative to the highest.
+fn spark(values: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let (low, high) = values
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), value| {
+            (low.min(*value), high.max(*value))
+        });
+    values
+        .iter()
+        .map(|value| {
+            let height = match high > low {
+                true => (value - low) / (high - low),
+                false => 1.0,
+            };
+            BARS[(height * (BARS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+/// How far a value is from the average of earlier ones, green when better and red when worse.
+fn delta(
+    value: f64,
+    earlier: impl Iterator<Item = f64>,
+    unit: &str,
+) -> ratatui::text::Span<'static> {
+    use ratatui::style::Stylize;
+
+    let earlier: Vec<f64> = earlier.collect();
+    let delta = (value - earlier.iter().sum::<f64>() / earlier.len() as f64).round();
+    let text = |sign: &str| format!("   {sign}{}{unit} vs last {}", delta.abs(), earlier.len());
+    match delta {
+        0.0 => text("±").dark_gray(),
+        delta if delta > 0.0 => text("+").green(),
+        _ => text("-").red(),
+    }
+}
+
+/// Puts text on the clipboard through the terminal with an OSC 52 escape sequence.
+fn copy(text: &str) {
+    const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let encoded: String = text
+        .as_bytes()
+        .chunks(3)
+        .flat_map(|chunk| {
+            let bytes = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+            (0..4).map(move |index| match index <= chunk.len() {
+                true => BASE64[(bits >> (18 - index * 6)) as usize & 63] as char,
+                false => '=',
+            })
+        })
+        .collect();
+
+    let mut stdout = std::io::stdout();
+    let _ = std::io::Write::write_fmt(&mut stdout, format_args!("\x1b]52;c;{encoded}\x07"));
+    let _ = std::io::Write::flush(&mut stdout);
+}
+
+impl Results {
+    /// Shows the result of a test that just ended, adds it to the history and records, and
+    /// unlocks the achievements it earned.
+    pub fn new(settings: crate::words::WordReq, result: TestResult) -> Self {
+        let mut unlocked = Vec::new();
+        let mut broken = crate::records::Broken::default();
+        let mut lesson = None;
+        let mut recent = Vec::new();
+
+        if result.keystrokes > 0 {
+            let mut history = crate::history::HISTORY.lock().unwrap();
+            recent = history
+                .recent(settings.mode, RECENT)
+                .iter()
+                .map(|entry| (entry.wpm, entry.accuracy))
+                .collect();
+            history.record(&settings, &result);
+            broken = crate::records::RECORDS
+                .lock()
+                .unwrap()
+                .record(&settings, &result);
+
+            if let Some(recording) = &result.recording {
+                crate::confusion::CONFUSIONS
+                    .lock()
+                    .unwrap()
+                    .record(recording);
+            }
+
+            lesson = settings
+                .lesson
+                .as_deref()
+                .and_then(crate::lessons::find)
+                .map(|lesson| {
+                    let passed = crate::lessons::COMPLETED
+                        .lock()
+                        .unwrap()
+                        .record(lesson, &result);
+                    (lesson, passed)
+                });
+
+            unlocked = crate::achievements::UNLOCKED.lock().unwrap().evaluate(
+                &crate::achievements::Context {
+                    history: &history,
+                    word_errors: &crate::stats::WORD_ERRORS.lock().unwrap(),
+                },
+            );
+        }
+
+        Self {
+            settings,
+            result,
+            copied: false,
+            unlocked,
+            broken,
+            playback: None,
+            submitted: None,
+            lesson,
+            recent,
+        }
+    }
+
+    /// Settings for a test of only the words missed in this one.
+    /// Timed and daily tests turn into word tests, so the practice ends once every word has been
+    /// typed and a daily challenge uses the missed words rather than the words of the day. Review
+    /// is turned off since finishing this test just scheduled every word of it for later, and
+    /// adaptive since the words are already the ones that need it.
+    fn practice_settings(&self) -> crate::words::WordReq {
+        let mut settings = self.settings.clone();
+        settings.only = self.result.missed.clone();
+        settings.lesson = None;
+        settings.review = false;
+        settings.adaptive = false;
+        if matches!(
+            settings.mode,
+            crate::words::Mode::Time
+                | crate::words::Mode::Daily
+                | crate::words::Mode::Quotes
+                | crate::words::Mode::Numbers
+        ) {
+            settings.mode = crate::words::Mode::Words;
+        }
+
+        settings
+    }
+
+    /// Settings for a new test, moving a fixed seed on so the words differ from this one.
+    fn next_settings(&self) -> crate::words::WordReq {
+        let mut settings = self.settings.clone();
+        settings.seed = settings.seed.map(|seed| seed.wrapping_add(1));
+
+        settings
+    }
+}
+
+impl Results {
+    /// The server results can be sent to, if there is one and this result can be sent.
+    fn server(&self) -> Option<&'static str> {
+        match (self.result.seed, self.settings.mode.is_timed_typing()) {
+            (Some(_), true) if cfg!(feature = "online") => {
+                crate::config::CONFIG.server.url.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Sends the result to the server in the config.
+    #[cfg(feature = "online")]
+    fn submit(&mut self, server: &str) {
+        let submission = crate::challenge::Submission {
+            name: crate::config::CONFIG.server.name.clone(),
+            seed: self.result.seed.unwrap_or_default(),
+            wpm: self.result.net_wpm,
+            accuracy: self.result.accuracy,
+            elapsed: self.result.elapsed.as_secs_f64(),
+        };
+
+        self.submitted = Some(match crate::challenge::submit(server, &submission) {
+            Ok(placement) => format!("submitted, ranked {} of {}", placement.rank, placement.of),
+            Err(err) => format!("could not submit: {err}"),
+        });
+    }
+
+    /// Charts the speed of every word over the test, with the words typed wrong marked on the line.
+    fn render_timeline(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        use ratatui::style::Stylize;
+
+        let speeds: Vec<(f64, f64)> = self
+            .result
+            .timeline
+            .iter()
+            .enumerate()
+            .map(|(index, (wpm, _))| ((index + 1) as f64, *wpm))
+            .collect();
+        let errors: Vec<(f64, f64)> = speeds
+            .iter()
+            .zip(&self.result.timeline)
+            .filter(|(_, (_, error))| *error)
+            .map(|(point, _)| *point)
+            .collect();
+
+        let top = speeds
+            .iter()
+            .map(|(_, wpm)| *wpm)
+            .fold(0.0, f64::max)
+            .ceil();
+        let words = speeds.len() as f64;
+        let theme = crate::theme::current();
+
+        let chart = ratatui::widgets::Chart::new(vec![
+            ratatui::widgets::Dataset::default()
+                .marker(ratatui::symbols::Marker::Braille)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(ratatui::style::Style::new().fg(theme.correct))
+                .data(&speeds),
+            ratatui::widgets::Dataset::default()
+                .marker(ratatui::symbols::Marker::Dot)
+                .graph_type(ratatui::widgets::GraphType::Scatter)
+                .style(ratatui::style::Style::new().fg(theme.error))
+                .data(&errors),
+        ])
+        .x_axis(
+            ratatui::widgets::Axis::default()
+                .title("word".dark_gray())
+                .bounds([1.0, words])
+                .labels(["1".to_string(), format!("{words}")]),
+        )
+        .y_axis(
+            ratatui::widgets::Axis::default()
+                .title("wpm".dark_gray())
+                .bounds([0.0, top])
+                .labels(["0".to_string(), format!("{top}")]),
+        );
+
+        frame.render_widget(chart, area);
+    }
+}
+
+impl crate::flow::Store for Results {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        if let Some(playback) = &mut self.playback {
+            if !playback.reduce(action) {
+                self.playback = None;
+            }
+
+            return None;
+        }
+
+        let keys = &crate::config::CONFIG.keys;
+        let enter = matches!(
+            action,
+            crate::flow::Action::Key(ratatui::crossterm::event::KeyEvent {
+                code: ratatui::crossterm::event::KeyCode::Enter,
+                ..
+            })
+        );
+
+        match action.char() {
+            _ if action.is(&keys.restart) => Some(crate::flow::State::Game {
+                settings: self.settings.clone(),
+            }),
+            _ if action.is(&keys.settings) => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            _ if action.is(&keys.quit) => Some(crate::flow::State::Exit),
+            _ if enter => Some(crate::flow::State::Game {
+                settings: self.next_settings(),
+            }),
+            Some('r') => Some(crate::flow::State::Game {
+                settings: self.settings.clone(),
+            }),
+            Some('n') => Some(crate::flow::State::Game {
+                settings: self.next_settings(),
+            }),
+            Some('s') => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            Some('m') if !self.result.missed.is_empty() => Some(crate::flow::State::Game {
+                settings: self.practice_settings(),
+            }),
+            Some('c') => {
+                if let Some(seed) = self.result.seed {
+                    copy(&seed.to_string());
+                    self.copied = true;
+                }
+                None
+            }
+            Some('v') => {
+                self.playback = self
+                    .result
+                    .recording
+                    .clone()
+                    .filter(|recording| !recording.strokes.is_empty())
+                    .map(|recording| crate::replay::Playback::new(*recording));
+                None
+            }
+            #[cfg(feature = "online")]
+            Some('u') if self.submitted.is_none() => {
+                if let Some(server) = self.server() {
+                    self.submit(server);
+                }
+                None
+            }
+            Some('h') => Some(crate::flow::State::History {
+                settings: self.settings.clone(),
+            }),
+            Some('q') => Some(crate::flow::State::Exit),
+            _ => None,
+        }
+    }
+
+    fn tick_rate(&self) -> std::time::Duration {
+        match &self.playback {
+            Some(playback) if playback.playing() => std::time::Duration::from_millis(30),
+            _ => crate::flow::TICK_RATE,
+        }
+    }
+
+    fn animating(&self) -> bool {
+        self.playback
+            .as_ref()
+            .is_some_and(|playback| playback.playing())
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        if let Some(playback) = &self.playback {
+            playback.render(frame, block.inner(frame.area()));
+            return;
+        }
+
+        let mode = self.settings.mode;
+        let mut text = ratatui::text::Text::default();
+
+        if self.settings.hardcore {
+            text.push_line(" hardcore ".bold().red().reversed());
+        }
+
+        let compared = !self.recent.is_empty();
+        if mode.is_timed_typing() {
+            let mut line =
+                ratatui::text::Line::from(format!("wpm: {:.0}", self.result.net_wpm).bold());
+            if compared {
+                line.push_span(delta(
+                    self.result.net_wpm,
+                    self.recent.iter().map(|(wpm, _)| *wpm),
+                    "",
+                ));
+            }
+            text.push_line(line);
+        }
+        if mode != crate::words::Mode::Choice {
+            text.push_line(format!("raw wpm: {:.0}", self.result.gross_wpm));
+        }
+        if mode != crate::words::Mode::Zen {
+            let mut line =
+                ratatui::text::Line::from(format!("accuracy: {:.1}%", self.result.accuracy));
+            if compared {
+                line.push_span(delta(
+                    self.result.accuracy,
+                    self.recent.iter().map(|(_, accuracy)| *accuracy),
+                    "%",
+                ));
+            }
+            text.push_line(line);
+            text.push_line(format!("errors: {}", self.result.errors));
+            text.push_line(format!(
+                "correct words: {}/{}",
+                self.result.words.0, self.result.words.1
+            ));
+        }
+        if let Some(consistency) = self.result.consistency {
+            text.push_line(format!("consistency: {consistency:.0}%"));
+        }
+        if compared && mode.is_timed_typing() {
+            let mut wpm: Vec<f64> = self.recent.iter().map(|(wpm, _)| *wpm).collect();
+            wpm.push(self.result.net_wpm);
+            let mut trend = spark(&wpm);
+            let last = trend.pop().unwrap();
+            text.push_line(ratatui::text::Line::from(vec![
+                "trend: ".into(),
+                trend.dark_gray(),
+                last.to_string().bold(),
+            ]));
+        }
+        if !self.result.introduced.is_empty() {
+            text.push_line(
+                format!("new words: {}", self.result.introduced.join(", "))
+                    .green()
+                    .bold(),
+            );
+        }
+        if let Some(source) = &self.result.source {
+            text.push_line(format!("from: {source}").italic());
+        }
+        // The mistakes of a blind test are shown now that it is over.
+        if let (true, Some(recording)) = (self.settings.blind, &self.result.recording) {
+            let input = recording.input_at(recording.length());
+            let typed = input.split_terminator(' ').count();
+            let target: String = recording
+                .target
+                .split_terminator(' ')
+                .take(typed)
+                .map(|word| format!("{word} "))
+                .collect();
+            let colored = crate::text::color_text(&target, &input, &recording.glyphs, false);
+
+            text.push_line(ratatui::text::Line::default());
+            crate::text::wrap(colored, block.inner(frame.area()).width)
+                .lines
+                .into_iter()
+                .for_each(|line| text.push_line(line));
+            text.push_line(ratatui::text::Line::default());
+        }
+        text.push_line(format!("keystrokes: {}", self.result.keystrokes));
+        text.push_line(format!("time: {:.1}s", self.result.elapsed.as_secs_f64()));
+        if let Some(seed) = self.result.seed {
+            match self.copied {
+                true => text.push_line(format!("seed: {seed} (copied)")),
+                false => text.push_line(format!("seed: {seed}   c: copy")),
+            }
+        }
+        match (&self.submitted, self.server()) {
+            (Some(submitted), _) => text.push_line(submitted.as_str()),
+            (None, Some(server)) => text.push_line(format!("u: submit to {server}")),
+            (None, None) => (),
+        }
+        if mode == crate::words::Mode::Daily {
+            let streak = crate::daily::streak(&crate::history::HISTORY.lock().unwrap());
+            let days = if streak == 1 { "day" } else { "days" };
+            text.push_line(format!("daily streak: {streak} {days}").bold());
+        }
+        text.push_line(ratatui::text::Line::default());
+        if let Some(previous) = self.broken.wpm {
+            text.push_line(
+                format!(
+                    "new personal best: {:.0} wpm, up from {previous:.0}",
+                    self.result.net_wpm
+                )
+                .green()
+                .bold(),
+            );
+        }
+        if let Some(previous) = self.broken.accuracy {
+            text.push_line(
+                format!(
+                    "new best accuracy: {:.1}%, up from {previous:.1}%",
+                    self.result.accuracy
+                )
+                .green()
+                .bold(),
+            );
+        }
+        match self.lesson {
+            Some((lesson, true)) => {
+                text.push_line(format!("lesson passed: {}", lesson.name).green().bold())
+            }
+            Some((lesson, false)) => text.push_line(format!(
+                "{} needs {:.0} wpm at {:.0}% accuracy to pass",
+                lesson.name, lesson.wpm, lesson.accuracy
+            )),
+            None => (),
+        }
+        self.unlocked.iter().for_each(|achievement| {
+            text.push_line(
+                format!(
+                    "achievement unlocked: {} ({})",
+                    achievement.name, achievement.description
+                )
+                .yellow()
+                .bold(),
+            );
+        });
+        match (self.result.missed.len(), self.settings.only.is_empty()) {
+            (0, true) => (),
+            (0, false) => text.push_line("every missed word cleared".green()),
+            (missed, _) => text.push_line(format!("m: practice {missed} missed words").yellow()),
+        }
+        if !self.result.keys.is_empty() {
+            text.push_line("missed keys:");
+            crate::keyboard::heatmap(&self.result.keys)
+                .into_iter()
+                .for_each(|line| text.push_line(line));
+            text.push_line(ratatui::text::Line::default());
+        }
+        let keys = &crate::config::CONFIG.keys;
+        text.push_line(
+            ratatui::text::Line::from(format!(
+                "r/{}: restart   n/enter: new test   {}s/{}: settings   h: history   q/{}: quit",
+                crate::keys::describe(&keys.restart),
+                match self.result.recording.is_some() {
+                    true => "v: replay   ",
+                    false => "",
+                },
+                crate::keys::describe(&keys.settings),
+                crate::keys::describe(&keys.quit),
+            ))
+            .dark_gray(),
+        );
+
+        let mut area = block.inner(frame.area());
+
+        if self.result.timeline.len() > 1 {
+            let [top, chart] = ratatui::layout::Layout::vertical([
+                ratatui::layout::Constraint::Length(text.height() as u16),
+                ratatui::layout::Constraint::Max(12),
+            ])
+            .areas(area);
+            area = top;
+
+            self.render_timeline(frame, chart);
+        }
+
+        frame.render_widget(ratatui::widgets::Paragraph::new(text), area);
+    }
+}
@@ -0,0 +1,194 @@
+/// How many words of each mastery level there are, and the words due for review with the most
+/// overdue first.
+pub struct Queue {
+    settings: crate::words::WordReq,
+    levels: Vec<(crate::stats::Level, usize)>,
+    /// Due words with their level and days overdue.
+    due: Vec<(&'static crate::words::WordData, crate::stats::Level, u64)>,
+    selected: usize,
+    /// The first row shown when last drawn, which clicks are counted from.
+    offset: std::cell::Cell<usize>,
+}
+
+impl Queue {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        // Picking the candidates takes the schedule lock, so it comes first.
+        let words = crate::words::candidates(&crate::words::WordReq {
+            review: false,
+            ..settings.clone()
+        });
+        let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+        let schedule = crate::srs::SCHEDULE.lock().unwrap();
+        let today = crate::stats::today();
+        let levels = crate::stats::Level::ALL
+            .iter()
+            .map(|level| {
+                let count = words
+                    .iter()
+                    .filter(|word| word_errors.level(&word.id) == *level)
+                    .count();
+                (*level, count)
+            })
+            .collect();
+
+        let mut due: Vec<_> = words
+            .iter()
+            .filter_map(|word| {
+                let card = schedule.cards.get(&word.id)?;
+                (card.due <= today).then(|| (*word, word_errors.level(&word.id), today - card.due))
+            })
+            .collect();
+        due.sort_by(|a, b| b.2.cmp(&a.2).then(a.1.cmp(&b.1)));
+
+        Self {
+            settings,
+            levels,
+            due,
+            selected: 0,
+            offset: std::cell::Cell::new(0),
+        }
+    }
+
+    /// One bar split between the levels by how many words each has.
+    fn gauge<'a>(&self, width: u16) -> ratatui::text::Line<'a> {
+        use ratatui::style::Stylize;
+
+        let total = self
+            .levels
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<usize>()
+            .max(1);
+        let mut line = ratatui::text::Line::default();
+
+        self.levels.iter().for_each(|(level, count)| {
+            let cells = (count * width as usize).div_ceil(total);
+            line.push_span(ratatui::text::Span::raw("█".repeat(cells)).fg(level.color()));
+        });
+
+        line
+    }
+}
+
+impl crate::flow::Store for Queue {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = self.due.len().max(1);
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+        // The list starts below the padding, the gauge, its legend and the list heading.
+        if let Some(click) = action.click() {
+            let row = (click.y as usize)
+                .checked_sub(6)
+                .map(|row| row + self.offset.get());
+            if let Some(row) = row.filter(|row| *row < self.due.len()) {
+                self.selected = row;
+            }
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('q') {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % rows,
+            KeyCode::Enter if !self.due.is_empty() => {
+                return Some(crate::flow::State::Game {
+                    settings: crate::words::WordReq {
+                        mode: crate::words::Mode::Words,
+                        review: true,
+                        ..self.settings.clone()
+                    },
+                })
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [gauge, legend, list, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(1),
+            ratatui::layout::Constraint::Length(3),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(self.gauge(gauge.width)),
+            gauge,
+        );
+
+        let mut line = ratatui::text::Line::default();
+        self.levels.iter().for_each(|(level, count)| {
+            line.push_span(
+                format!("■ {count} {}   ", format!("{level:?}").to_lowercase()).fg(level.color()),
+            );
+        });
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(vec![
+                line,
+                ratatui::text::Line::from(format!("{} words due for review", self.due.len())),
+            ]),
+            legend,
+        );
+
+        let rows = self.due.iter().map(|(word, level, overdue)| {
+            let overdue = match overdue {
+                0 => "today".to_string(),
+                1 => "1 day overdue".to_string(),
+                days => format!("{days} days overdue"),
+            };
+
+            ratatui::widgets::Row::new([
+                ratatui::text::Span::raw(word.word.clone()),
+                format!("{level:?}").to_lowercase().fg(level.color()),
+                ratatui::text::Span::raw(overdue),
+                ratatui::text::Span::raw(word.definition(&self.settings.lang).unwrap_or_default())
+                    .dark_gray(),
+            ])
+        });
+        let table = ratatui::widgets::Table::new(
+            rows,
+            [
+                ratatui::layout::Constraint::Length(12),
+                ratatui::layout::Constraint::Length(10),
+                ratatui::layout::Constraint::Length(18),
+                ratatui::layout::Constraint::Min(0),
+            ],
+        )
+        .header(ratatui::widgets::Row::new(["word", "level", "due", "definition"]).dark_gray())
+        .highlight_style(ratatui::style::Style::new().reversed());
+
+        let mut state = ratatui::widgets::TableState::new()
+            .with_offset(self.offset.get())
+            .with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, list, &mut state);
+        self.offset.set(state.offset());
+
+        let help_line = match self.due.is_empty() {
+            true => "nothing due   q: back",
+            false => "up/down: select   enter: review the due words   q: back",
+        };
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(help_line.dark_gray()),
+            help,
+        );
+    }
+}
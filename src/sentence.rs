@@ -0,0 +1,190 @@
+/// Parts of speech as tagged in the pu definitions, which are the only ones the word data has.
+#[derive(Clone, Copy)]
+enum Part {
+    Noun,
+    Verb,
+    Adjective,
+    Preposition,
+}
+
+impl Part {
+    fn tag(&self) -> &'static str {
+        match self {
+            Part::Noun => "NOUN",
+            Part::Verb => "VERB",
+            Part::Adjective => "ADJECTIVE",
+            Part::Preposition => "PREPOSITION",
+        }
+    }
+}
+
+/// Whether a word can be used as the part of speech, going by its English pu definition where
+/// each use starts a line with its tag.
+fn is(word: &crate::words::WordData, part: Part) -> bool {
+    word.pu_verbatim
+        .as_ref()
+        .and_then(|pu| pu.get("en"))
+        .is_some_and(|en| {
+            en.lines().any(|line| {
+                line.strip_prefix(part.tag())
+                    .is_some_and(|rest| rest.starts_with(' '))
+            })
+        })
+}
+
+/// The words a sentence is built from, split by part of speech.
+struct Pools {
+    nouns: Vec<&'static crate::words::WordData>,
+    verbs: Vec<&'static crate::words::WordData>,
+    adjectives: Vec<&'static crate::words::WordData>,
+    prepositions: Vec<&'static crate::words::WordData>,
+}
+
+impl Pools {
+    /// Sorts the allowed words by part of speech. Parts none of them can fill are taken from every
+    /// word instead, so any selection of words still makes sentences.
+    fn new(settings: &crate::words::WordReq) -> Self {
+        let candidates = crate::words::candidates(settings);
+        let pool = |part: Part| {
+            let allowed: Vec<&'static crate::words::WordData> = candidates
+                .iter()
+                .copied()
+                .filter(|word| is(word, part))
+                .collect();
+
+            match allowed.is_empty() {
+                true => crate::words::WORDS
+                    .iter()
+                    .filter(|word| is(word, part))
+                    .filter(|word| !crate::banned::BANNED.lock().unwrap().contains(&word.id))
+                    .collect(),
+                false => allowed,
+            }
+        };
+
+        Self {
+            nouns: pool(Part::Noun),
+            verbs: pool(Part::Verb),
+            adjectives: pool(Part::Adjective),
+            prepositions: pool(Part::Preposition),
+        }
+    }
+}
+
+/// Builds sentences one word at a time, drawing content words from the pools.
+struct Builder<'r, R: rand::Rng> {
+    pools: Pools,
+    rng: &'r mut R,
+    words: Vec<&'static crate::words::WordData>,
+}
+
+impl<R: rand::Rng> Builder<'_, R> {
+    fn push(&mut self, part: Part) {
+        use rand::seq::SliceRandom;
+
+        let pool = match part {
+            Part::Noun => &self.pools.nouns,
+            Part::Verb => &self.pools.verbs,
+            Part::Adjective => &self.pools.adjectives,
+            Part::Preposition => &self.pools.prepositions,
+        };
+        if let Some(word) = pool.choose(self.rng) {
+            self.words.push(word);
+        }
+    }
+
+    /// Pushes a particle or pronoun, which the grammar needs whatever words are allowed.
+    fn particle(&mut self, word: &str) {
+        if let Some(word) = crate::dict::lookup(word) {
+            self.words.push(word);
+        }
+    }
+
+    /// A noun, followed by an adjective half the time once sentences aren't simple.
+    fn noun_phrase(&mut self, complexity: Complexity) {
+        self.push(Part::Noun);
+        if complexity != Complexity::Simple && self.rng.gen_bool(0.5) {
+            self.push(Part::Adjective);
+        }
+    }
+
+    /// subject (li) predicate (e object) (preposition noun), with "la" context in front of
+    /// complex sentences now and then.
+    fn sentence(&mut self, complexity: Complexity) {
+        if complexity == Complexity::Complex && self.rng.gen_bool(0.3) {
+            self.noun_phrase(complexity);
+            self.particle("la");
+        }
+
+        // "li" is left out after mi or sina alone.
+        match self.rng.gen_range(0..4) {
+            0 => self.particle("mi"),
+            1 => self.particle("sina"),
+            _ => {
+                self.noun_phrase(complexity);
+                self.particle("li");
+            }
+        }
+
+        match complexity == Complexity::Simple || self.rng.gen_bool(0.3) {
+            true => match self.rng.gen_bool(0.5) {
+                true => self.push(Part::Verb),
+                false => self.push(Part::Adjective),
+            },
+            false => {
+                self.push(Part::Verb);
+                self.particle("e");
+                self.noun_phrase(complexity);
+            }
+        }
+
+        if complexity == Complexity::Complex && self.rng.gen_bool(0.5) {
+            self.push(Part::Preposition);
+            self.noun_phrase(complexity);
+        }
+    }
+}
+
+/// How involved generated sentences are.
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Complexity {
+    /// A subject and a verb or adjective.
+    Simple,
+    /// Adds objects with "e" and adjectives after nouns.
+    Compound,
+    /// Adds prepositional phrases and context with "la".
+    Complex,
+}
+
+impl Complexity {
+    pub const ALL: [Complexity; 3] = [
+        Complexity::Simple,
+        Complexity::Compound,
+        Complexity::Complex,
+    ];
+}
+
+/// Generates whole sentences until they hold at least `settings.n` words.
+pub fn generate(
+    settings: &crate::words::WordReq,
+    rng: &mut impl rand::Rng,
+) -> Vec<&'static crate::words::WordData> {
+    let mut builder = Builder {
+        pools: Pools::new(settings),
+        rng,
+        words: Vec::new(),
+    };
+
+    while builder.words.len() < settings.n {
+        let before = builder.words.len();
+        builder.sentence(settings.complexity);
+        if builder.words.len() == before {
+            break;
+        }
+    }
+
+    builder.words
+}
@@ -0,0 +1,124 @@
+/// Longest result body taken, far more than a submission needs.
+const MAX_BODY: u64 = 4096;
+
+/// The challenge the settings give, with a seed picked when they have none so submissions can be
+/// matched to it.
+fn challenge(settings: crate::words::WordReq) -> crate::challenge::Challenge {
+    let seed = crate::words::seed(&settings);
+    let settings = crate::words::WordReq {
+        seed: Some(seed),
+        ..settings
+    };
+
+    crate::challenge::Challenge {
+        words: crate::words::subset(&settings, &mut crate::words::seeded_rng(seed))
+            .iter()
+            .map(|word| word.id.clone())
+            .collect(),
+        settings,
+    }
+}
+
+/// Hosts one challenge over HTTP until stopped:
+///
+/// - `GET /challenge` gives the challenge as json.
+/// - `GET /results` gives the results sent in so far, fastest first.
+/// - `POST /results` takes a result as json and answers with its rank.
+///
+/// Results are only kept in memory.
+pub fn run(settings: crate::words::WordReq, address: &str) -> Result<(), String> {
+    let challenge = challenge(settings);
+    let seed = challenge.settings.seed.unwrap_or_default();
+    let mut results: Vec<crate::challenge::Submission> = Vec::new();
+
+    let server = tiny_http::Server::http(address)
+        .map_err(|err| format!("could not listen on {address}: {err}"))?;
+    println!(
+        "serving a challenge of {} words with seed {seed} on http://{address}",
+        challenge.words.len()
+    );
+
+    for mut request in server.incoming_requests() {
+        let (status, body) = match (request.method(), request.url()) {
+            (tiny_http::Method::Get, "/challenge") => {
+                (200, serde_json::to_string(&challenge).unwrap())
+            }
+            (tiny_http::Method::Get, "/results") => (200, serde_json::to_string(&results).unwrap()),
+            (tiny_http::Method::Post, "/results") => {
+                let mut json = String::new();
+                let mut body = std::io::Read::take(request.as_reader(), MAX_BODY + 1);
+                let submission = std::io::Read::read_to_string(&mut body, &mut json)
+                    .map_err(|err| err.to_string())
+                    .and_then(|read| match read as u64 > MAX_BODY {
+                        true => Err(format!("results are at most {MAX_BODY} bytes")),
+                        false => Ok(read),
+                    })
+                    .and_then(|_| {
+                        serde_json::from_str::<crate::challenge::Submission>(&json)
+                            .map_err(|err| err.to_string())
+                    });
+
+                match submission {
+                    Ok(submission) if submission.seed != seed => {
+                        (400, "that is not the current challenge".to_string())
+                    }
+                    Ok(submission) => {
+                        println!(
+                            "{}: {:.0} wpm, {:.1}%",
+                            submission.name, submission.wpm, submission.accuracy
+                        );
+                        let rank = results
+                            .iter()
+                            .take_while(|result| result.wpm >= submission.wpm)
+                            .count();
+                        results.insert(rank, submission);
+
+                        let placement = crate::challenge::Placement {
+                            rank: rank + 1,
+                            of: results.len(),
+                        };
+                        (200, serde_json::to_string(&placement).unwrap())
+                    }
+                    Err(err) => (400, err),
+                }
+            }
+            _ => (404, "not found".to_string()),
+        };
+
+        let content_type = match status {
+            200 => "application/json",
+            _ => "text/plain",
+        };
+        let response = tiny_http::Response::from_string(body)
+            .with_status_code(status)
+            .with_header(tiny_http::Header::from_bytes("Content-Type", content_type).unwrap());
+        let _ = request.respond(response);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn challenges_are_typed_as_served() {
+        let settings = crate::words::WordReq {
+            seed: Some(7),
+            n: 20,
+            ..Default::default()
+        };
+        let challenge = super::challenge(settings.clone());
+        assert_eq!(challenge.words, super::challenge(settings).words);
+        assert_eq!(challenge.words.len(), 20);
+
+        let taken = crate::words::WordReq {
+            fixed: challenge.words.clone(),
+            ..challenge.settings
+        };
+        let ids: Vec<String> = crate::words::fixed(&taken)
+            .iter()
+            .map(|word| word.id.clone())
+            .collect();
+        assert_eq!(ids, challenge.words);
+    }
+}
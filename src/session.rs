@@ -0,0 +1,159 @@
+/// Seconds between saves of the test in progress.
+pub const AUTOSAVE: std::time::Duration = std::time::Duration::from_secs(5);
+
+const FILE: &str = "session.json";
+
+/// A test in progress as it is kept on disk, so it can be picked up again after a crash or a
+/// closed terminal.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Session {
+    /// Seconds since the unix epoch when it was saved.
+    pub time: u64,
+    pub settings: crate::words::WordReq,
+    pub ids: Vec<String>,
+    pub glyphs: Vec<Option<char>>,
+    pub words: String,
+    pub definitions: Vec<String>,
+    pub index: usize,
+    pub input: String,
+    pub durations: Vec<std::time::Duration>,
+    /// Time since the first key, which the clock of a timed test goes on from.
+    pub elapsed: std::time::Duration,
+    pub keystrokes: usize,
+    pub refused: Vec<usize>,
+    pub strokes: Vec<(f64, crate::replay::Stroke)>,
+    pub seed: u64,
+    pub source: Option<String>,
+}
+
+impl Session {
+    /// The test left unfinished last time, if there is one.
+    pub fn load() -> Option<Self> {
+        crate::storage::read(FILE, |json| {
+            serde_json::from_str(json)
+                .map(Some)
+                .map_err(|err| err.to_string())
+        })
+    }
+
+    /// Saves the test, which happens while it is being typed, so a data directory that can't be
+    /// written is only reported.
+    pub fn save(&self) {
+        crate::storage::write(
+            FILE,
+            serde_json::to_string(self).map_err(|err| err.to_string()),
+        );
+    }
+
+    /// Forgets the saved test, once it is finished or left on purpose.
+    pub fn clear() {
+        if let Some(path) = crate::stats::data_file(FILE) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    /// How long ago it was saved, like "5 minutes ago".
+    fn age(&self) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let (count, unit) = match now.saturating_sub(self.time) {
+            seconds if seconds < 60 => return "just now".to_string(),
+            seconds if seconds < 3600 => (seconds / 60, "minute"),
+            seconds if seconds < 86400 => (seconds / 3600, "hour"),
+            seconds => (seconds / 86400, "day"),
+        };
+
+        match count {
+            1 => format!("1 {unit} ago"),
+            count => format!("{count} {unit}s ago"),
+        }
+    }
+}
+
+/// Offers to carry on with the test left unfinished last time.
+pub struct Offer {
+    settings: crate::words::WordReq,
+    session: Option<Session>,
+}
+
+impl Offer {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self {
+            settings,
+            session: Session::load(),
+        }
+    }
+}
+
+impl crate::flow::Store for Offer {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('n') {
+            Session::clear();
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match (key.code, &self.session) {
+            (KeyCode::Enter, Some(_)) => Some(crate::flow::State::Resume {
+                settings: self.settings.clone(),
+            }),
+            (KeyCode::Enter, None) => Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [title, body, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(2),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new("unfinished test".bold()),
+            title,
+        );
+
+        let text = match &self.session {
+            Some(session) => format!(
+                "a {} test from {}, with {} of {} words typed",
+                format!("{:?}", session.settings.mode).to_lowercase(),
+                session.age(),
+                session.index,
+                session.ids.len(),
+            ),
+            None => "the unfinished test could not be read".to_string(),
+        };
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(text).wrap(ratatui::widgets::Wrap { trim: false }),
+            body,
+        );
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                format!(
+                    "enter: resume it   n/{}: discard it",
+                    crate::keys::describe(&crate::config::CONFIG.keys.quit)
+                )
+                .dark_gray(),
+            ),
+            help,
+        );
+    }
+}
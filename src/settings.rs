@@ -0,0 +1,359 @@
+/// Settings that are adjusted with left and right instead of being toggled.
+#[derive(Clone, Copy)]
+enum Value {
+    Theme,
+    Marks,
+    Lang,
+    Mode,
+    Words,
+    Time,
+    Hint,
+    HintDelay,
+    Strictness,
+    Space,
+    Direction,
+    Complexity,
+    Letters,
+    MinLength,
+    MaxLength,
+    WithLetters,
+    WithoutLetters,
+}
+
+const VALUES: [Value; 17] = [
+    Value::Mode,
+    Value::Words,
+    Value::Time,
+    Value::Hint,
+    Value::HintDelay,
+    Value::Strictness,
+    Value::Space,
+    Value::Direction,
+    Value::Complexity,
+    Value::Letters,
+    Value::MinLength,
+    Value::MaxLength,
+    Value::WithLetters,
+    Value::WithoutLetters,
+    Value::Lang,
+    Value::Theme,
+    Value::Marks,
+];
+
+impl Value {
+    fn label(&self, settings: &crate::words::WordReq) -> String {
+        match self {
+            Value::Mode => format!("< {:?} > mode", settings.mode).to_lowercase(),
+            Value::Words => format!("< {} > words", settings.n),
+            Value::Time => format!("< {} > seconds", settings.time),
+            Value::Hint => format!("< {:?} > hints", settings.hint).to_lowercase(),
+            Value::HintDelay => {
+                format!("< {} > seconds before a delayed hint", settings.hint_delay)
+            }
+            Value::Strictness => format!("< {:?} > on errors", settings.strictness).to_lowercase(),
+            Value::Space => format!("< {:?} > space mid-word", settings.space).to_lowercase(),
+            Value::Direction => format!("< {:?} > in quizzes", settings.direction).to_lowercase(),
+            Value::Complexity => format!("< {:?} > sentences", settings.complexity).to_lowercase(),
+            Value::Letters => format!("< {} > drill letters", settings.letters),
+            Value::MinLength => match settings.min_length {
+                0 => "< any > fewest letters in a word".to_string(),
+                length => format!("< {length} > fewest letters in a word"),
+            },
+            Value::MaxLength => match settings.max_length {
+                0 => "< any > most letters in a word".to_string(),
+                length => format!("< {length} > most letters in a word"),
+            },
+            Value::WithLetters => match settings.with_letters.as_str() {
+                "" => "< any > letters words must have one of".to_string(),
+                letters => format!("< {letters} > letters words must have one of"),
+            },
+            Value::WithoutLetters => match settings.without_letters.as_str() {
+                "" => "< none > letters words must not have".to_string(),
+                letters => format!("< {letters} > letters words must not have"),
+            },
+            Value::Lang => format!("< {} > definition language", settings.lang),
+            Value::Theme => format!("< {} > theme", crate::theme::THEME.read().unwrap()),
+            Value::Marks => format!(
+                "< {:?} > mistakes marked by",
+                crate::theme::MARKS.read().unwrap()
+            )
+            .to_lowercase(),
+        }
+    }
+
+    fn adjust(&self, settings: &mut crate::words::WordReq, forward: bool) {
+        use crate::words::{Direction, Hint, Mode, Space, Strictness, HINT_DELAYS, TIMES};
+
+        fn step<T: PartialEq + Clone>(options: &[T], current: &T, forward: bool) -> T {
+            let index = options.iter().position(|x| x == current).unwrap_or(0);
+            let len = options.len();
+
+            match forward {
+                true => options[(index + 1) % len].clone(),
+                false => options[(index + len - 1) % len].clone(),
+            }
+        }
+
+        match self {
+            Value::Mode => settings.mode = step(&Mode::ALL, &settings.mode, forward),
+            Value::Words => match forward {
+                true => settings.n += 1,
+                false => settings.n = settings.n.saturating_sub(1).max(1),
+            },
+            Value::Time => settings.time = step(&TIMES, &settings.time, forward),
+            Value::Hint => settings.hint = step(&Hint::ALL, &settings.hint, forward),
+            Value::HintDelay => {
+                settings.hint_delay = step(&HINT_DELAYS, &settings.hint_delay, forward)
+            }
+            Value::Strictness => {
+                settings.strictness = step(&Strictness::ALL, &settings.strictness, forward)
+            }
+            Value::Space => settings.space = step(&Space::ALL, &settings.space, forward),
+            Value::Direction => {
+                settings.direction = step(&Direction::ALL, &settings.direction, forward)
+            }
+            Value::Complexity => {
+                settings.complexity = step(
+                    &crate::sentence::Complexity::ALL,
+                    &settings.complexity,
+                    forward,
+                )
+            }
+            Value::Letters => {
+                settings.letters = step(
+                    &crate::drill::LETTERS.map(str::to_string),
+                    &settings.letters,
+                    forward,
+                )
+            }
+            Value::MinLength => match forward {
+                true => settings.min_length += 1,
+                false => settings.min_length = settings.min_length.saturating_sub(1),
+            },
+            Value::MaxLength => match forward {
+                true => settings.max_length += 1,
+                false => settings.max_length = settings.max_length.saturating_sub(1),
+            },
+            Value::WithLetters => {
+                settings.with_letters = step(
+                    &crate::words::LETTER_SETS.map(str::to_string),
+                    &settings.with_letters,
+                    forward,
+                )
+            }
+            Value::WithoutLetters => {
+                settings.without_letters = step(
+                    &crate::words::LETTER_SETS.map(str::to_string),
+                    &settings.without_letters,
+                    forward,
+                )
+            }
+            Value::Lang => {
+                settings.lang = step(&crate::words::languages(), &settings.lang, forward)
+            }
+            Value::Theme => {
+                let mut theme = crate::theme::THEME.write().unwrap();
+                *theme = step(&crate::theme::names(), &theme, forward);
+            }
+            Value::Marks => {
+                let mut marks = crate::theme::MARKS.write().unwrap();
+                *marks = step(&crate::theme::Marks::ALL, &marks, forward);
+            }
+        }
+    }
+}
+
+pub struct Settings {
+    settings: crate::words::WordReq,
+    selected: usize,
+}
+
+impl Settings {
+    pub fn new(mut settings: crate::words::WordReq) -> Self {
+        // Practicing missed words and lessons end when going back to the settings.
+        settings.only.clear();
+        settings.lesson = None;
+
+        Self {
+            settings,
+            selected: 0,
+        }
+    }
+
+    fn rows(&mut self) -> usize {
+        VALUES.len() + self.settings.flags().len()
+    }
+}
+
+impl crate::flow::Store for Settings {
+    fn settings(&self) -> Option<crate::words::WordReq> {
+        Some(self.settings.clone())
+    }
+
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let rows = self.rows();
+
+        if let Some(scroll) = action.scroll() {
+            self.selected = (self.selected as isize + scroll).clamp(0, rows as isize - 1) as usize;
+        }
+
+        // Options are drawn one per line below the top padding, with the arrows of values at the
+        // left edge, so a click on "<" steps back and anywhere else on the line steps forward.
+        if let Some(click) = action.click() {
+            let row = (click.y as usize)
+                .checked_sub(1)
+                .filter(|row| *row < rows)?;
+            self.selected = row;
+
+            match VALUES.get(row) {
+                Some(value) => value.adjust(&mut self.settings, click.x > 1),
+                None => {
+                    if let Some((_, value)) =
+                        self.settings.flags().into_iter().nth(row - VALUES.len())
+                    {
+                        *value = !*value;
+                    }
+                }
+            }
+        }
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) {
+            return Some(crate::flow::State::Exit);
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = (self.selected + rows - 1) % rows,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1) % rows,
+            KeyCode::Char(' ') => {
+                if let Some(index) = self.selected.checked_sub(VALUES.len()) {
+                    if let Some((_, value)) = self.settings.flags().into_iter().nth(index) {
+                        *value = !*value;
+                    }
+                }
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                if let Some(value) = VALUES.get(self.selected) {
+                    value.adjust(&mut self.settings, false);
+                }
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                if let Some(value) = VALUES.get(self.selected) {
+                    value.adjust(&mut self.settings, true);
+                }
+            }
+            KeyCode::Enter => {
+                return Some(crate::flow::State::Game {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('/') => {
+                return Some(crate::flow::State::Browse {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('p') => {
+                return Some(crate::flow::State::Progress {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('t') => {
+                return Some(crate::flow::State::Trophies {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('S') => {
+                return Some(crate::flow::State::Dashboard {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('L') => {
+                return Some(crate::flow::State::Lessons {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('R') => {
+                return Some(crate::flow::State::Review {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('W') => {
+                return Some(crate::flow::State::Report {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('C') => {
+                return Some(crate::flow::State::Confusions {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('N') => {
+                return Some(crate::flow::State::Editor {
+                    settings: self.settings.clone(),
+                    word: None,
+                })
+            }
+            KeyCode::Char('H') => {
+                return Some(crate::flow::State::History {
+                    settings: self.settings.clone(),
+                })
+            }
+            KeyCode::Char('q') => return Some(crate::flow::State::Exit),
+            _ => (),
+        }
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let mut settings = self.settings.clone();
+
+        let mut lines: Vec<ratatui::text::Line> = VALUES
+            .iter()
+            .map(|value| ratatui::text::Line::from(value.label(&self.settings)))
+            .chain(settings.flags().into_iter().map(|(label, value)| {
+                ratatui::text::Line::from(format!("[{}] {label}", if *value { 'x' } else { ' ' }))
+            }))
+            .collect();
+        lines[self.selected] = lines[self.selected].clone().reversed();
+        lines.push(ratatui::text::Line::default());
+        lines.push(ratatui::text::Line::from(format!(
+            "{} words available",
+            crate::words::candidates(&self.settings).len()
+        )));
+        if self.settings.mode == crate::words::Mode::Learn {
+            lines.push(ratatui::text::Line::from(format!(
+                "{} of {} words unlocked in the course",
+                crate::learn::COURSE.lock().unwrap().unlocked,
+                crate::learn::order().len()
+            )));
+        }
+        lines.push(ratatui::text::Line::from(format!(
+            "{} words due for review",
+            crate::srs::SCHEDULE.lock().unwrap().due_count()
+        )));
+        lines.push(
+            ratatui::text::Line::from(format!(
+                "up/down: select   space/click: toggle   left/right: change   enter: start   {}: all commands   {}: keys   /: dictionary   C: confused letters   H: history   L: lessons   N: new word   R: review   S: stats   W: weak words   p: progress   t: trophies   q: quit",
+                crate::keys::describe(&crate::config::CONFIG.keys.palette),
+                crate::keys::describe(&crate::config::CONFIG.keys.help),
+            ))
+            .dark_gray(),
+        );
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false }),
+            block.inner(frame.area()),
+        );
+    }
+}
@@ -0,0 +1,229 @@
+/// Set for a simulation, so the config and data files are neither read nor written and every run
+/// of a script goes the same way.
+pub static HEADLESS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Keeps this process away from the config and data files from here on.
+/// Has to be called before the config or any data is first used to have an effect on it.
+pub fn headless() {
+    HEADLESS.store(true, std::sync::atomic::Ordering::Relaxed);
+}
+
+pub fn is_headless() -> bool {
+    HEADLESS.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// One step of a script.
+#[derive(Debug, PartialEq)]
+enum Step {
+    /// Time passing before each key from here on.
+    Pace(std::time::Duration),
+    /// Each character pressed in turn.
+    Type(String),
+    Key(crate::keys::Binding),
+    /// Time passing without a key, followed by a tick.
+    Wait(std::time::Duration),
+}
+
+/// Reads a script, one step per line:
+///
+/// ```text
+/// # a comment
+/// pace 200        milliseconds before each key from here on
+/// type toki pona  presses each character
+/// key backspace   presses a key the way the config writes them, like "ctrl+r" or "esc"
+/// wait 1000       lets time pass without a key
+/// ```
+fn parse(script: &str) -> Result<Vec<Step>, String> {
+    let millis = |number: &str, line: usize| {
+        number
+            .trim()
+            .parse()
+            .map(std::time::Duration::from_millis)
+            .map_err(|_| format!("line {line}: {number:?} is not a number of milliseconds"))
+    };
+
+    script
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_start()))
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| {
+            let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match command {
+                "pace" => Ok(Step::Pace(millis(rest, number)?)),
+                "type" => Ok(Step::Type(rest.to_string())),
+                "key" => crate::keys::Binding::try_from(rest.trim().to_string())
+                    .map(Step::Key)
+                    .map_err(|err| format!("line {number}: {err}")),
+                "wait" => Ok(Step::Wait(millis(rest, number)?)),
+                command => Err(format!("line {number}: unknown step {command:?}")),
+            }
+        })
+        .collect()
+}
+
+/// Plays a script against the page for `state` on a screen of the given size, with the clock only
+/// moving as the script says, and returns what is on the screen at the end.
+pub fn run(
+    state: crate::flow::State,
+    script: &str,
+    width: u16,
+    height: u16,
+) -> Result<String, String> {
+    use crate::flow::Action;
+
+    headless();
+    let steps = parse(script)?;
+
+    crate::clock::simulate();
+    let mut terminal =
+        ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+    let mut dispatcher = crate::flow::Dispatcher::new(state);
+
+    let mut pace = std::time::Duration::ZERO;
+    let mut actions = Vec::new();
+    for step in steps {
+        match step {
+            Step::Pace(duration) => pace = duration,
+            Step::Type(text) => actions.extend(text.chars().map(|c| {
+                let key = ratatui::crossterm::event::KeyEvent::from(
+                    ratatui::crossterm::event::KeyCode::Char(c),
+                );
+                (pace, Action::Key(key))
+            })),
+            Step::Key(binding) => actions.push((pace, Action::Key(binding.event()))),
+            Step::Wait(duration) => actions.push((duration, Action::Tick)),
+        }
+    }
+
+    terminal.draw(|frame| dispatcher.render(frame)).unwrap();
+    for (duration, action) in actions {
+        crate::clock::advance(duration);
+        if !dispatcher.dispatch(&action) {
+            break;
+        }
+        terminal.draw(|frame| dispatcher.render(frame)).unwrap();
+    }
+
+    let buffer = terminal.backend().buffer();
+    let screen = (0..buffer.area.height)
+        .map(|y| {
+            let line: String = (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect();
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(screen)
+}
+
+#[cfg(test)]
+mod tests {
+    fn settings() -> crate::words::WordReq {
+        crate::words::WordReq {
+            only: vec!["toki".to_string(); 3],
+            repeat: true,
+            n: 3,
+            seed: Some(1),
+            ..Default::default()
+        }
+    }
+
+    fn play(script: &str) -> String {
+        super::run(
+            crate::flow::State::Game {
+                settings: settings(),
+            },
+            script,
+            100,
+            30,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_steps() {
+        use super::Step;
+
+        let steps =
+            super::parse("# warm up\npace 150\n\ntype toki pona\nkey ctrl+r\nwait 20").unwrap();
+        assert_eq!(
+            steps,
+            [
+                Step::Pace(std::time::Duration::from_millis(150)),
+                Step::Type("toki pona".to_string()),
+                Step::Key(crate::keys::Binding::try_from("ctrl+r".to_string()).unwrap()),
+                Step::Wait(std::time::Duration::from_millis(20)),
+            ]
+        );
+
+        assert!(super::parse("pace fast")
+            .unwrap_err()
+            .starts_with("line 1:"));
+        assert!(super::parse("type a\njump")
+            .unwrap_err()
+            .starts_with("line 2:"));
+    }
+
+    #[test]
+    fn scores_a_clean_test() {
+        // The clock starts at the first key, so 14 keys 100ms apart take 1.3s.
+        let screen = play("pace 100\ntype toki toki toki");
+        assert!(screen.contains("wpm: 138"));
+        assert!(screen.contains("accuracy: 100.0%"));
+        assert!(screen.contains("errors: 0"));
+        assert!(screen.contains("correct words: 3/3"));
+        assert!(screen.contains("time: 1.3s"));
+    }
+
+    #[test]
+    fn counts_mistakes() {
+        let screen = play("pace 100\ntype tiki toki toki");
+        assert!(screen.contains("accuracy: 91.7%"));
+        assert!(screen.contains("errors: 1"));
+        assert!(screen.contains("correct words: 2/3"));
+    }
+
+    #[test]
+    fn leaves_idle_time_out() {
+        // The test pauses after a while without keys, and the key that resumes it isn't typed.
+        let screen = play("pace 100\ntype toki \nwait 20000\ntype ttoki toki");
+        assert!(screen.contains("time: 1.3s"));
+        assert!(screen.contains("correct words: 3/3"));
+    }
+
+    #[test]
+    fn types_numbers_with_their_punctuation() {
+        // A seed whose sentences ask something, so a "?" has to be typed as text and not taken as
+        // a key binding.
+        let settings = |seed| crate::words::WordReq {
+            mode: crate::words::Mode::Numbers,
+            n: 5,
+            seed: Some(seed),
+            ..Default::default()
+        };
+        let (seed, tokens) = (0..)
+            .map(|seed| {
+                let tokens =
+                    crate::numbers::generate(&settings(seed), &mut crate::words::seeded_rng(seed));
+                (seed, tokens)
+            })
+            .find(|(_, tokens)| tokens.iter().any(|(token, _)| token.ends_with('?')))
+            .unwrap();
+        let text: Vec<&str> = tokens.iter().map(|(token, _)| token.as_str()).collect();
+
+        let screen = super::run(
+            crate::flow::State::Game {
+                settings: settings(seed),
+            },
+            &format!("pace 100\ntype {}", text.join(" ")),
+            100,
+            30,
+        )
+        .unwrap();
+        assert!(screen.contains("accuracy: 100.0%"));
+        assert!(screen.contains(&format!("correct words: {0}/{0}", text.len())));
+    }
+}
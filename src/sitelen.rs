@@ -0,0 +1,162 @@
+/// Word ids in the order of their UCSUR codepoints, starting at U+F1900.
+const BLOCK: [&str; 137] = [
+    "a",
+    "akesi",
+    "ala",
+    "alasa",
+    "ale",
+    "anpa",
+    "ante",
+    "anu",
+    "awen",
+    "e",
+    "en",
+    "esun",
+    "ijo",
+    "ike",
+    "ilo",
+    "insa",
+    "jaki",
+    "jan",
+    "jelo",
+    "jo",
+    "kala",
+    "kalama",
+    "kama",
+    "kasi",
+    "ken",
+    "kepeken",
+    "kili",
+    "kiwen",
+    "ko",
+    "kon",
+    "kule",
+    "kulupu",
+    "kute",
+    "la",
+    "lape",
+    "laso",
+    "lawa",
+    "len",
+    "lete",
+    "li",
+    "lili",
+    "linja",
+    "lipu",
+    "loje",
+    "lon",
+    "luka",
+    "lukin",
+    "lupa",
+    "ma",
+    "mama",
+    "mani",
+    "meli",
+    "mi",
+    "mije",
+    "moku",
+    "moli",
+    "monsi",
+    "mu",
+    "mun",
+    "musi",
+    "mute",
+    "nanpa",
+    "nasa",
+    "nasin",
+    "nena",
+    "ni",
+    "nimi",
+    "noka",
+    "o",
+    "olin",
+    "ona",
+    "open",
+    "pakala",
+    "pali",
+    "palisa",
+    "pan",
+    "pana",
+    "pi",
+    "pilin",
+    "pimeja",
+    "pini",
+    "pipi",
+    "poka",
+    "poki",
+    "pona",
+    "pu",
+    "sama",
+    "seli",
+    "selo",
+    "seme",
+    "sewi",
+    "sijelo",
+    "sike",
+    "sin",
+    "sina",
+    "sinpin",
+    "sitelen",
+    "sona",
+    "soweli",
+    "suli",
+    "suno",
+    "supa",
+    "suwi",
+    "tan",
+    "taso",
+    "tawa",
+    "telo",
+    "tenpo",
+    "toki",
+    "tomo",
+    "tu",
+    "unpa",
+    "uta",
+    "utala",
+    "walo",
+    "wan",
+    "waso",
+    "wawa",
+    "weka",
+    "wile",
+    "namako",
+    "kin",
+    "oko",
+    "kipisi",
+    "leko",
+    "monsuta",
+    "tonsi",
+    "jasima",
+    "kijetesantakalu",
+    "soko",
+    "meso",
+    "epiku",
+    "kokosila",
+    "lanpan",
+    "n",
+    "misikeke",
+    "ku",
+];
+
+/// Words added to UCSUR after the main block, starting at U+F19A0.
+const EXTENDED: [&str; 4] = ["pake", "apeja", "majuna", "powe"];
+
+/// The word of a sitelen pona glyph in the UCSUR private use area, if it is one.
+pub fn word(glyph: char) -> Option<&'static str> {
+    match glyph as u32 {
+        codepoint @ 0xF1900..0xF19A0 => BLOCK.get((codepoint - 0xF1900) as usize).copied(),
+        codepoint @ 0xF19A0..0xF1A00 => EXTENDED.get((codepoint - 0xF19A0) as usize).copied(),
+        _ => None,
+    }
+}
+
+/// The sitelen pona glyph of a word in the UCSUR private use area, if it has one.
+pub fn glyph(id: &str) -> Option<char> {
+    let codepoint = match BLOCK.iter().position(|word| *word == id) {
+        Some(index) => 0xF1900 + index as u32,
+        None => 0xF19A0 + EXTENDED.iter().position(|word| *word == id)? as u32,
+    };
+
+    char::from_u32(codepoint)
+}
@@ -0,0 +1,160 @@
+/// Sample rate the built-in sounds are made at.
+const RATE: u32 = 44100;
+
+pub enum Sound {
+    /// A keystroke.
+    Key,
+    /// A keystroke that was a mistake.
+    Error,
+    /// A recording of a word, downloaded the first time it is played.
+    Word(crate::words::Audio),
+}
+
+/// A decoded or generated sound, ready to be played any number of times.
+#[derive(Clone)]
+struct Clip {
+    channels: u16,
+    rate: u32,
+    samples: Vec<f32>,
+}
+
+impl Clip {
+    /// A sound `seconds` long made from a function of time, faded out so it doesn't pop.
+    fn generate(seconds: f32, wave: impl Fn(f32) -> f32) -> Self {
+        let len = (seconds * RATE as f32) as usize;
+
+        Self {
+            channels: 1,
+            rate: RATE,
+            samples: (0..len)
+                .map(|i| {
+                    let t = i as f32 / RATE as f32;
+                    wave(t) * (1.0 - i as f32 / len as f32).powi(3)
+                })
+                .collect(),
+        }
+    }
+
+    fn load(path: &std::path::Path) -> Option<Self> {
+        use rodio::Source;
+
+        let file = std::io::BufReader::new(std::fs::File::open(path).ok()?);
+        let decoder = rodio::Decoder::new_wav(file).ok()?;
+
+        Some(Self {
+            channels: decoder.channels(),
+            rate: decoder.sample_rate(),
+            samples: decoder.convert_samples().collect(),
+        })
+    }
+
+    fn scaled(mut self, volume: f32) -> Self {
+        self.samples.iter_mut().for_each(|sample| *sample *= volume);
+        self
+    }
+}
+
+/// Cheap white noise, which is all a click needs.
+fn noise(t: f32) -> f32 {
+    let x = ((t * RATE as f32) as u32)
+        .wrapping_mul(0x9e37_79b9)
+        .rotate_left(13);
+    x as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+fn sine(t: f32, frequency: f32) -> f32 {
+    (t * frequency * std::f32::consts::TAU).sin()
+}
+
+/// The key and error sounds of a pack, falling back to "click" for unknown packs.
+fn pack(name: &str) -> (Clip, Clip) {
+    let error = Clip::generate(0.12, |t| (sine(t, 180.0) + sine(t, 190.0)).signum() * 0.3);
+
+    match name {
+        "thock" => (
+            Clip::generate(0.05, |t| sine(t, 140.0) * 0.8 + noise(t) * 0.1),
+            error,
+        ),
+        "beep" => (Clip::generate(0.04, |t| sine(t, 880.0) * 0.5), error),
+        "click" => (Clip::generate(0.015, |t| noise(t) * 0.6), error),
+        custom => {
+            let dir = directories::ProjectDirs::from("", "", "sona")
+                .map(|dirs| dirs.config_dir().join("sounds").join(custom));
+            let load = |file: &str| dir.as_ref().and_then(|dir| Clip::load(&dir.join(file)));
+
+            match load("key.wav") {
+                Some(key) => (key, load("error.wav").unwrap_or(error)),
+                None => pack("click"),
+            }
+        }
+    }
+}
+
+/// Where a recording is kept once downloaded, named by a hash of its link since the author and
+/// link come from word packs and can't be trusted as parts of a path.
+fn audio_path(audio: &crate::words::Audio) -> Option<std::path::PathBuf> {
+    let dirs = directories::ProjectDirs::from("", "", "sona")?;
+    let file = format!("{:016x}", crate::words::hash(audio.link.as_bytes()));
+
+    Some(dirs.cache_dir().join("audio").join(file))
+}
+
+/// Plays a recording of a word, fetching it first if needed. Recordings that can't be had are
+/// skipped, since the word can still be typed without them.
+fn play_word(handle: &rodio::OutputStreamHandle, audio: &crate::words::Audio) -> Option<()> {
+    use rodio::Source;
+
+    let path = audio_path(audio)?;
+    #[cfg(feature = "update")]
+    crate::update::download(&audio.link, &path).ok()?;
+
+    let file = std::io::BufReader::new(std::fs::File::open(path).ok()?);
+    let decoder = rodio::Decoder::new(file).ok()?;
+
+    handle.play_raw(decoder.convert_samples()).ok()
+}
+
+/// Sends sounds to a thread that owns the audio output, since the output can't be shared.
+/// If no output device opens, sounds go nowhere.
+static PLAYER: std::sync::LazyLock<std::sync::mpsc::Sender<Sound>> =
+    std::sync::LazyLock::new(|| {
+        let config = &crate::config::CONFIG.sound;
+
+        let (sender, receiver) = std::sync::mpsc::channel::<Sound>();
+        let volume = config.volume.clamp(0.0, 1.0);
+        let (key, error) = pack(&config.pack);
+        let (key, error) = (key.scaled(volume), error.scaled(volume));
+
+        std::thread::spawn(move || {
+            let Ok((_stream, handle)) = rodio::OutputStream::try_default() else {
+                return;
+            };
+
+            for sound in receiver {
+                let clip = match sound {
+                    Sound::Key => &key,
+                    Sound::Error => &error,
+                    Sound::Word(audio) => {
+                        play_word(&handle, &audio);
+                        continue;
+                    }
+                };
+                let _ = handle.play_raw(rodio::buffer::SamplesBuffer::new(
+                    clip.channels,
+                    clip.rate,
+                    clip.samples.clone(),
+                ));
+            }
+        });
+
+        sender
+    });
+
+/// Plays a sound without waiting for it. Keystrokes are only heard when sound is turned on.
+pub fn play(sound: Sound) {
+    if matches!(sound, Sound::Key | Sound::Error) && !crate::config::CONFIG.sound.enabled {
+        return;
+    }
+
+    let _ = PLAYER.send(sound);
+}
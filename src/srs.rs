@@ -0,0 +1,124 @@
+/// SM-2 review state for a single word.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub struct Card {
+    pub ease: f64,
+    /// Days until the next review.
+    pub interval: u64,
+    pub repetitions: u32,
+    /// Day the card is next due, counted in days since the unix epoch.
+    pub due: u64,
+}
+
+impl Default for Card {
+    fn default() -> Self {
+        Self {
+            ease: 2.5,
+            interval: 0,
+            repetitions: 0,
+            due: 0,
+        }
+    }
+}
+
+impl Card {
+    /// Updates the card with a review graded from 0 (forgotten) to 5 (perfect).
+    pub fn review(&mut self, quality: u8, today: u64) {
+        let quality = quality.min(5) as f64;
+
+        if quality < 3.0 {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u64,
+            };
+            self.repetitions += 1;
+        }
+
+        self.ease = (self.ease + 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02)).max(1.3);
+        self.due = today + self.interval;
+    }
+}
+
+/// Review schedule of every word that has been practiced.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Schedule {
+    pub cards: std::collections::HashMap<String, Card>,
+}
+
+pub static SCHEDULE: std::sync::LazyLock<std::sync::Mutex<Schedule>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(Schedule::load()));
+
+impl Schedule {
+    pub fn load() -> Self {
+        crate::storage::load("schedule.toml")
+    }
+
+    pub fn save(&self) {
+        crate::storage::save("schedule.toml", self);
+    }
+
+    pub fn review(&mut self, id: &str, quality: u8) {
+        self.cards
+            .entry(id.to_string())
+            .or_default()
+            .review(quality, crate::stats::today());
+    }
+
+    /// Words only become due once they have been practiced at least once.
+    pub fn is_due(&self, id: &str) -> bool {
+        self.cards
+            .get(id)
+            .is_some_and(|card| card.due <= crate::stats::today())
+    }
+
+    pub fn due_count(&self) -> usize {
+        let today = crate::stats::today();
+
+        self.cards.values().filter(|card| card.due <= today).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn spaces_reviews_out_and_starts_over_when_forgotten() {
+        let mut card = super::Card::default();
+
+        card.review(4, 100);
+        assert_eq!((card.interval, card.repetitions, card.due), (1, 1, 101));
+        card.review(4, 101);
+        assert_eq!((card.interval, card.repetitions, card.due), (6, 2, 107));
+        // A good but not perfect answer leaves the ease where it was.
+        card.review(4, 107);
+        assert_eq!(card.ease, 2.5);
+        assert_eq!((card.interval, card.repetitions, card.due), (15, 3, 122));
+
+        card.review(1, 122);
+        assert_eq!((card.interval, card.repetitions, card.due), (1, 0, 123));
+        assert!(card.ease < 2.5);
+    }
+
+    #[test]
+    fn ease_has_a_floor() {
+        let mut card = super::Card::default();
+        (0..20).for_each(|day| card.review(0, day));
+
+        assert_eq!(card.ease, 1.3);
+    }
+
+    #[test]
+    fn only_practiced_words_come_due() {
+        let mut schedule = super::Schedule::default();
+        schedule.review("toki", 4);
+
+        assert!(!schedule.is_due("toki"));
+        assert!(!schedule.is_due("pona"));
+
+        schedule.cards.get_mut("toki").unwrap().due = crate::stats::today();
+        assert!(schedule.is_due("toki"));
+        assert_eq!(schedule.due_count(), 1);
+    }
+}
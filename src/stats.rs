@@ -0,0 +1,250 @@
+/// Hits and misses per word id, accumulated across sessions.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct WordErrors {
+    pub words: std::collections::HashMap<String, (i32, i32)>,
+    /// Moving average of seconds spent per character of each word.
+    #[serde(default)]
+    pub speed: std::collections::HashMap<String, f64>,
+    /// Moving average of how often each word was typed right lately, from 0 to 1.
+    #[serde(default)]
+    pub recent: std::collections::HashMap<String, f64>,
+    /// Seconds since the unix epoch when each word was last typed, which merging goes by.
+    #[serde(default)]
+    pub updated: std::collections::HashMap<String, u64>,
+}
+
+/// How well a word is known, going by how it has been typed lately.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    /// Never typed.
+    New,
+    Learning,
+    /// Mostly typed right.
+    Familiar,
+    /// Typed right almost always and at least as fast as the average word.
+    Mastered,
+}
+
+impl Level {
+    pub const ALL: [Level; 4] = [
+        Level::New,
+        Level::Learning,
+        Level::Familiar,
+        Level::Mastered,
+    ];
+
+    pub fn color(&self) -> ratatui::style::Color {
+        match self {
+            Level::New => ratatui::style::Color::DarkGray,
+            Level::Learning => ratatui::style::Color::Red,
+            Level::Familiar => ratatui::style::Color::Yellow,
+            Level::Mastered => ratatui::style::Color::Green,
+        }
+    }
+
+    /// How much more often adaptive tests pick a word of this level.
+    fn weight(&self) -> f64 {
+        match self {
+            Level::New => 1.0,
+            Level::Learning => 2.0,
+            Level::Familiar => 1.0,
+            Level::Mastered => 0.5,
+        }
+    }
+
+    /// The SM-2 grade of typing a word of this level right, so well known words are scheduled
+    /// further out.
+    pub fn quality(&self) -> u8 {
+        match self {
+            Level::New | Level::Learning => 3,
+            Level::Familiar => 4,
+            Level::Mastered => 5,
+        }
+    }
+}
+
+pub static WORD_ERRORS: std::sync::LazyLock<std::sync::Mutex<WordErrors>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(WordErrors::load("word_errors.toml")));
+
+/// The same stats for the phrases of phrase mode, kept apart from the words they are made of.
+pub static PHRASE_ERRORS: std::sync::LazyLock<std::sync::Mutex<WordErrors>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(WordErrors::load("phrase_errors.toml")));
+
+/// Path of a file inside the user data directory, creating the directory if needed.
+pub fn data_file(name: &str) -> Option<std::path::PathBuf> {
+    #[cfg(test)]
+    let dir = test_dir("data");
+    #[cfg(not(test))]
+    let dir = match crate::simulate::is_headless() {
+        true => return None,
+        false => directories::ProjectDirs::from("", "", "sona")?
+            .data_dir()
+            .to_path_buf(),
+    };
+    std::fs::create_dir_all(&dir).ok()?;
+
+    Some(dir.join(name))
+}
+
+/// Path of a file inside the user cache directory, creating the directory if needed. What is kept
+/// there can be made again from other data.
+pub fn cache_file(name: &str) -> Option<std::path::PathBuf> {
+    #[cfg(test)]
+    let dir = test_dir("cache");
+    #[cfg(not(test))]
+    let dir = match crate::simulate::is_headless() {
+        true => return None,
+        false => directories::ProjectDirs::from("", "", "sona")?
+            .cache_dir()
+            .to_path_buf(),
+    };
+    std::fs::create_dir_all(&dir).ok()?;
+
+    Some(dir.join(name))
+}
+
+/// A directory in the temporary directory that stands in for one of the user's while the tests
+/// run, headless or not, so they start from no data and never write over any.
+#[cfg(test)]
+fn test_dir(kind: &str) -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("sona-test-{}", std::process::id()))
+        .join(kind)
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Days since the unix epoch in UTC.
+pub fn today() -> u64 {
+    now() / 86400
+}
+
+impl WordErrors {
+    /// Loads the stats from storage, starting fresh if there are none yet.
+    pub fn load(file: &str) -> Self {
+        crate::storage::STORAGE.load_word_errors(file)
+    }
+
+    pub fn save(&self, file: &str) {
+        crate::storage::STORAGE.save_word_errors(file, self);
+    }
+
+    pub fn record(&mut self, id: &str, hit: bool) {
+        self.updated.insert(id.to_string(), now());
+
+        let (hits, misses) = self.words.entry(id.to_string()).or_default();
+
+        match hit {
+            true => *hits += 1,
+            false => *misses += 1,
+        }
+
+        let sample = hit as u8 as f64;
+        self.recent
+            .entry(id.to_string())
+            .and_modify(|recent| *recent = *recent * 0.7 + sample * 0.3)
+            .or_insert(sample);
+    }
+
+    /// Takes the stats of every word typed more recently in `other`, returning how many.
+    /// Words with stats from before times were kept count as typed at the epoch.
+    pub fn merge(&mut self, other: &WordErrors) -> usize {
+        let newer: Vec<&String> = other
+            .words
+            .keys()
+            .filter(|id| {
+                other.updated.get(*id).copied().unwrap_or_default()
+                    > self.updated.get(*id).copied().unwrap_or_default()
+                    || !self.words.contains_key(*id)
+            })
+            .collect();
+
+        newer.iter().for_each(|id| {
+            self.words.insert(id.to_string(), other.words[*id]);
+            [
+                (&mut self.speed, &other.speed),
+                (&mut self.recent, &other.recent),
+            ]
+            .into_iter()
+            .for_each(|(mine, theirs)| match theirs.get(*id) {
+                Some(value) => {
+                    mine.insert(id.to_string(), *value);
+                }
+                None => {
+                    mine.remove(*id);
+                }
+            });
+            match other.updated.get(*id) {
+                Some(updated) => self.updated.insert(id.to_string(), *updated),
+                None => self.updated.remove(*id),
+            };
+        });
+
+        newer.len()
+    }
+
+    pub fn record_time(&mut self, id: &str, time: std::time::Duration, len: usize) {
+        let sample = time.as_secs_f64() / len.max(1) as f64;
+
+        self.speed
+            .entry(id.to_string())
+            .and_modify(|speed| *speed = *speed * 0.7 + sample * 0.3)
+            .or_insert(sample);
+    }
+
+    /// Average seconds per character over every word typed.
+    fn average_speed(&self) -> f64 {
+        match self.speed.is_empty() {
+            true => 1.0,
+            false => (self.speed.values().sum::<f64>() / self.speed.len() as f64).max(f64::EPSILON),
+        }
+    }
+
+    /// How well a word is known. Stats from before recent accuracy was kept count as their
+    /// overall accuracy.
+    pub fn level(&self, id: &str) -> Level {
+        let Some((hits, misses)) = self.words.get(id).copied() else {
+            return Level::New;
+        };
+        let recent = self
+            .recent
+            .get(id)
+            .copied()
+            .unwrap_or(hits as f64 / (hits + misses).max(1) as f64);
+        let fast = self
+            .speed
+            .get(id)
+            .is_none_or(|speed| *speed <= self.average_speed());
+
+        match recent {
+            _ if hits >= 3 && recent >= 0.9 && fast => Level::Mastered,
+            _ if hits >= 2 && recent >= 0.7 => Level::Familiar,
+            _ => Level::Learning,
+        }
+    }
+
+    /// Whether a word has been typed right often enough, and rarely enough wrong, to count as known.
+    pub fn mastered(&self, id: &str) -> bool {
+        self.level(id) == Level::Mastered
+    }
+
+    /// How strongly a word should be favoured when picking weak words.
+    /// Unseen words sit in the middle so they still get introduced.
+    pub fn weight(&self, id: &str) -> f64 {
+        let (hits, misses) = self.words.get(id).copied().unwrap_or_default();
+        let miss_rate = (misses as f64 + 1.0) / ((hits + misses) as f64 + 2.0);
+
+        let slowness = self
+            .speed
+            .get(id)
+            .map_or(1.0, |speed| speed / self.average_speed());
+
+        (miss_rate * 4.0 + slowness) * self.level(id).weight()
+    }
+}
@@ -0,0 +1,378 @@
+/// Which storage the history and word stats are kept in.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    Files,
+    /// Only there with the "sqlite" feature, and files otherwise.
+    Sqlite,
+}
+
+/// Where the history, the keystrokes of tests and the word stats are kept between runs.
+pub trait Storage: Send + Sync {
+    fn load_history(&self) -> crate::history::History;
+
+    fn save_history(&self, history: &crate::history::History);
+
+    /// Loads the stats kept under `name`, which is the word stats or the phrase stats.
+    fn load_word_errors(&self, name: &str) -> crate::stats::WordErrors;
+
+    fn save_word_errors(&self, name: &str, errors: &crate::stats::WordErrors);
+}
+
+pub static STORAGE: std::sync::LazyLock<Box<dyn Storage>> =
+    std::sync::LazyLock::new(|| match crate::config::CONFIG.storage {
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => match Sqlite::open() {
+            Some(sqlite) => Box::new(sqlite),
+            None => Box::new(Files),
+        },
+        _ => Box::new(Files),
+    });
+
+/// A TOML file in the data directory for each kind of data, without the keystrokes of tests.
+pub struct Files;
+
+/// Files that couldn't be read or moved aside, which are left as they are instead of saved over.
+static KEPT: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+/// Reads a file of the data directory with `parse`, starting empty when there is none. A file that
+/// can't be parsed is moved aside to a .bak next to it, so saving the empty data later doesn't
+/// write over it, and left as it is when it can't be moved.
+pub fn read<T: Default>(file: &str, parse: impl FnOnce(&str) -> Result<T, String>) -> T {
+    let Some(path) = crate::stats::data_file(file) else {
+        return T::default();
+    };
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        return T::default();
+    };
+
+    parse(&text).unwrap_or_else(|err| {
+        let mut backup = path.clone().into_os_string();
+        backup.push(".bak");
+        match std::fs::rename(&path, &backup) {
+            Ok(()) => eprintln!(
+                "sona: could not read {}, moved it to {}: {err}",
+                path.display(),
+                std::path::Path::new(&backup).display()
+            ),
+            Err(_) => {
+                eprintln!(
+                    "sona: could not read {}, leaving it as it is: {err}",
+                    path.display()
+                );
+                if let Ok(mut kept) = KEPT.lock() {
+                    kept.push(file.to_string());
+                }
+            }
+        }
+        T::default()
+    })
+}
+
+/// Files that couldn't be written, which are only reported the first time since some are saved
+/// over and over while a test is typed.
+static UNWRITTEN: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+/// Writes a file of the data directory, saying so when it can't be written instead of failing,
+/// since this runs on the way out and from the panic hook. Files left as they were when they
+/// couldn't be read aren't written.
+pub fn write(file: &str, text: Result<String, String>) {
+    if KEPT
+        .lock()
+        .is_ok_and(|kept| kept.iter().any(|kept| kept == file))
+    {
+        return;
+    }
+    if let Some(path) = crate::stats::data_file(file) {
+        let written =
+            text.and_then(|text| std::fs::write(&path, text).map_err(|err| err.to_string()));
+        let Err(err) = written else {
+            return;
+        };
+        let Ok(mut unwritten) = UNWRITTEN.lock() else {
+            return;
+        };
+        if !unwritten.iter().any(|unwritten| unwritten == file) {
+            eprintln!("sona: could not save {}: {err}", path.display());
+            unwritten.push(file.to_string());
+        }
+    }
+}
+
+/// Reads a TOML file of the data directory like `read`.
+pub fn load<T: serde::de::DeserializeOwned + Default>(file: &str) -> T {
+    read(file, |toml| {
+        toml::from_str(toml).map_err(|err| err.to_string())
+    })
+}
+
+/// Writes a TOML file of the data directory like `write`.
+pub fn save<T: serde::Serialize>(file: &str, value: &T) {
+    write(file, toml::to_string(value).map_err(|err| err.to_string()));
+}
+
+impl Storage for Files {
+    fn load_history(&self) -> crate::history::History {
+        load("history.toml")
+    }
+
+    fn save_history(&self, history: &crate::history::History) {
+        save("history.toml", history);
+    }
+
+    fn load_word_errors(&self, name: &str) -> crate::stats::WordErrors {
+        load(name)
+    }
+
+    fn save_word_errors(&self, name: &str, errors: &crate::stats::WordErrors) {
+        save(name, errors);
+    }
+}
+
+/// Changes to the database in order, each run once. The version reached is kept in the
+/// database's user_version.
+#[cfg(feature = "sqlite")]
+const MIGRATIONS: [&str; 2] = [
+    "
+    CREATE TABLE tests (
+        id INTEGER PRIMARY KEY,
+        time INTEGER NOT NULL,
+        mode TEXT NOT NULL,
+        wpm REAL NOT NULL,
+        accuracy REAL NOT NULL,
+        entry TEXT NOT NULL
+    );
+    CREATE TABLE strokes (
+        test INTEGER NOT NULL REFERENCES tests (id),
+        at REAL NOT NULL,
+        stroke TEXT NOT NULL
+    );
+    CREATE INDEX strokes_test ON strokes (test);
+    CREATE TABLE word_stats (
+        list TEXT NOT NULL,
+        id TEXT NOT NULL,
+        hits INTEGER NOT NULL,
+        misses INTEGER NOT NULL,
+        speed REAL,
+        recent REAL,
+        PRIMARY KEY (list, id)
+    );
+",
+    "
+    ALTER TABLE word_stats ADD COLUMN updated INTEGER;
+",
+];
+
+/// A SQLite database in the data directory, which also keeps every keystroke of every test.
+/// Saving the history writes just the tests not in it yet, told apart by when they ended and their
+/// speed.
+#[cfg(feature = "sqlite")]
+pub struct Sqlite(std::sync::Mutex<rusqlite::Connection>);
+
+#[cfg(feature = "sqlite")]
+impl Sqlite {
+    /// Opens the database, creating it or bringing its tables up to date as needed.
+    fn open() -> Option<Self> {
+        let mut connection =
+            rusqlite::Connection::open(crate::stats::data_file("sona.db")?).ok()?;
+
+        let version: usize = connection
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .ok()?;
+        let transaction = connection.transaction().ok()?;
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            transaction.execute_batch(migration).ok()?;
+            transaction
+                .pragma_update(None, "user_version", index + 1)
+                .ok()?;
+        }
+        transaction.commit().ok()?;
+
+        Some(Self(std::sync::Mutex::new(connection)))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Storage for Sqlite {
+    /// Tests without their keystrokes, which are written to the strokes table to be queried but
+    /// never read back. An empty database starts from the history file, so switching over keeps it.
+    fn load_history(&self) -> crate::history::History {
+        let connection = self.0.lock().unwrap();
+        let mut statement = connection
+            .prepare("SELECT entry FROM tests ORDER BY time, id")
+            .unwrap();
+        let tests: Vec<crate::history::Entry> = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .filter_map(|entry| serde_json::from_str(&entry.ok()?).ok())
+            .collect();
+
+        match tests.is_empty() {
+            true => Files.load_history(),
+            false => crate::history::History { tests },
+        }
+    }
+
+    fn save_history(&self, history: &crate::history::History) {
+        if let Err(err) = self.insert_tests(history) {
+            eprintln!("sona: could not save the history: {err}");
+        }
+    }
+
+    fn load_word_errors(&self, name: &str) -> crate::stats::WordErrors {
+        let connection = self.0.lock().unwrap();
+        let mut statement = connection
+            .prepare(
+                "SELECT id, hits, misses, speed, recent, updated FROM word_stats WHERE list = ?1",
+            )
+            .unwrap();
+        let mut rows = statement.query([name]).unwrap();
+
+        let mut errors = crate::stats::WordErrors::default();
+        while let Some(row) = rows.next().unwrap() {
+            let id: String = row.get(0).unwrap();
+            if let Some(speed) = row.get(3).unwrap() {
+                errors.speed.insert(id.clone(), speed);
+            }
+            if let Some(recent) = row.get(4).unwrap() {
+                errors.recent.insert(id.clone(), recent);
+            }
+            if let Some(updated) = row.get::<_, Option<i64>>(5).unwrap() {
+                errors.updated.insert(id.clone(), updated as u64);
+            }
+            errors
+                .words
+                .insert(id, (row.get(1).unwrap(), row.get(2).unwrap()));
+        }
+
+        match errors.words.is_empty() {
+            true => Files.load_word_errors(name),
+            false => errors,
+        }
+    }
+
+    fn save_word_errors(&self, name: &str, errors: &crate::stats::WordErrors) {
+        if let Err(err) = self.upsert_word_errors(name, errors) {
+            eprintln!("sona: could not save the stats of {name}: {err}");
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Sqlite {
+    /// Writes the tests of the history that aren't in the database yet, with their keystrokes.
+    fn insert_tests(&self, history: &crate::history::History) -> rusqlite::Result<()> {
+        let mut connection = self.0.lock().unwrap();
+        let saved: std::collections::HashSet<(i64, u64)> = connection
+            .prepare("SELECT time, wpm FROM tests")?
+            .query_map([], |row| Ok((row.get(0)?, row.get::<_, f64>(1)?.to_bits())))?
+            .filter_map(Result::ok)
+            .collect();
+
+        let transaction = connection.transaction()?;
+        for entry in history
+            .tests
+            .iter()
+            .filter(|entry| !saved.contains(&(entry.time as i64, entry.wpm.to_bits())))
+        {
+            transaction.execute(
+                "INSERT INTO tests (time, mode, wpm, accuracy, entry) VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![
+                    entry.time as i64,
+                    format!("{:?}", entry.settings.mode).to_lowercase(),
+                    entry.wpm,
+                    entry.accuracy,
+                    serde_json::to_string(entry).unwrap(),
+                ],
+            )?;
+
+            let test = transaction.last_insert_rowid();
+            for (at, stroke) in entry
+                .recording
+                .iter()
+                .flat_map(|recording| &recording.strokes)
+            {
+                transaction.execute(
+                    "INSERT INTO strokes (test, at, stroke) VALUES (?1, ?2, ?3)",
+                    rusqlite::params![test, at, serde_json::to_string(stroke).unwrap()],
+                )?;
+            }
+        }
+        transaction.commit()
+    }
+
+    /// Writes the stats kept under `name`, replacing what was there for the same words.
+    fn upsert_word_errors(
+        &self,
+        name: &str,
+        errors: &crate::stats::WordErrors,
+    ) -> rusqlite::Result<()> {
+        let ids: std::collections::BTreeSet<&String> = errors
+            .words
+            .keys()
+            .chain(errors.speed.keys())
+            .chain(errors.recent.keys())
+            .collect();
+
+        let mut connection = self.0.lock().unwrap();
+        let transaction = connection.transaction()?;
+        for id in ids {
+            let (hits, misses) = errors.words.get(id).copied().unwrap_or_default();
+            transaction.execute(
+                "INSERT INTO word_stats (list, id, hits, misses, speed, recent, updated)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                    ON CONFLICT (list, id) DO UPDATE SET hits = excluded.hits,
+                    misses = excluded.misses, speed = excluded.speed, recent = excluded.recent,
+                    updated = excluded.updated",
+                rusqlite::params![
+                    name,
+                    id,
+                    hits,
+                    misses,
+                    errors.speed.get(id),
+                    errors.recent.get(id),
+                    errors.updated.get(id).map(|updated| *updated as i64),
+                ],
+            )?;
+        }
+        transaction.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn banned(words: &[&str]) -> crate::banned::Banned {
+        crate::banned::Banned {
+            words: words.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn saved_data_loads_back() {
+        super::save("saved.toml", &banned(&["toki", "pona"]));
+        let loaded: crate::banned::Banned = super::load("saved.toml");
+
+        assert_eq!(loaded.words, banned(&["toki", "pona"]).words);
+        // Files that aren't there yet load empty.
+        assert!(super::load::<crate::banned::Banned>("missing.toml")
+            .words
+            .is_empty());
+    }
+
+    #[test]
+    fn unreadable_data_is_moved_aside() {
+        let path = crate::stats::data_file("broken.toml").unwrap();
+        std::fs::write(&path, "words = 3").unwrap();
+
+        let loaded: crate::banned::Banned = super::load("broken.toml");
+        assert!(loaded.words.is_empty());
+        assert!(!path.exists());
+        let backup = crate::stats::data_file("broken.toml.bak").unwrap();
+        assert_eq!(std::fs::read_to_string(backup).unwrap(), "words = 3");
+
+        // The file is saved anew in place of the one moved aside.
+        super::save("broken.toml", &banned(&["toki"]));
+        let loaded: crate::banned::Banned = super::load("broken.toml");
+        assert_eq!(loaded.words, banned(&["toki"]).words);
+    }
+}
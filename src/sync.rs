@@ -0,0 +1,61 @@
+/// The history and word stats of one machine, written by `sona sync export` and merged into
+/// another's by `sona sync import`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Bundle {
+    /// Seconds since the unix epoch when it was written.
+    exported: u64,
+    history: Vec<crate::history::Entry>,
+    words: crate::stats::WordErrors,
+    phrases: crate::stats::WordErrors,
+}
+
+/// Writes everything there is to merge to a file.
+pub fn export(path: &std::path::Path) -> Result<(), String> {
+    let bundle = Bundle {
+        exported: crate::stats::now(),
+        history: crate::history::HISTORY.lock().unwrap().tests.clone(),
+        words: crate::stats::WordErrors::load("word_errors.toml"),
+        phrases: crate::stats::WordErrors::load("phrase_errors.toml"),
+    };
+
+    std::fs::write(path, serde_json::to_string(&bundle).unwrap())
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Merges a bundle into the history and word stats. Tests missing here are added, told apart by
+/// when they ended and their speed, and a word's stats are taken from whichever side typed it
+/// last. Returns how many tests and words were taken from the bundle.
+pub fn import(path: &std::path::Path) -> Result<(usize, usize), String> {
+    let json = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let bundle: Bundle = serde_json::from_str(&json)
+        .map_err(|err| format!("{}: not a sona sync bundle: {err}", path.display()))?;
+
+    let mut history = crate::history::HISTORY.lock().unwrap();
+    let known: std::collections::HashSet<(u64, u64)> = history
+        .tests
+        .iter()
+        .map(|entry| (entry.time, entry.wpm.to_bits()))
+        .collect();
+    let before = history.tests.len();
+    history.tests.extend(
+        bundle
+            .history
+            .into_iter()
+            .filter(|entry| !known.contains(&(entry.time, entry.wpm.to_bits()))),
+    );
+    let tests = history.tests.len() - before;
+    history.tests.sort_by_key(|entry| entry.time);
+    history.save();
+
+    let mut words = 0;
+    for (file, theirs) in [
+        ("word_errors.toml", bundle.words),
+        ("phrase_errors.toml", bundle.phrases),
+    ] {
+        let mut mine = crate::stats::WordErrors::load(file);
+        words += mine.merge(&theirs);
+        mine.save(file);
+    }
+
+    Ok((tests, words))
+}
@@ -0,0 +1,423 @@
+/// Extends iterators by first wraping its elements with Some and then chains an infinite iterator of None elements.
+fn extend<I: Clone, T: Iterator<Item = I>>(iter: T) -> impl Iterator<Item = Option<I>> {
+    iter.map(Some).chain(std::iter::repeat(None))
+}
+
+/// Zips two iterators so that the resulting iterator is the length of the longest iterator.
+/// Their items are wraped with Some so that if one itterator runs out it can return None
+pub fn full_zip<IA: Clone, IB: Clone, A: Iterator<Item = IA>, B: Iterator<Item = IB>>(
+    a: A,
+    b: B,
+) -> impl std::iter::Iterator<Item = (Option<IA>, Option<IB>)> {
+    extend(a)
+        .zip(extend(b))
+        .take_while(|(a, b)| a.is_some() || b.is_some())
+}
+
+/// The user-perceived characters of a word, so an accented letter typed with a combining mark
+/// counts as one.
+pub fn graphemes(word: &str) -> impl Iterator<Item = &str> {
+    unicode_segmentation::UnicodeSegmentation::graphemes(word, true)
+}
+
+/// Stands in for a space typed into the middle of a word, so it doesn't split the word in two.
+pub const INSERTED_SPACE: char = '␣';
+
+/// Underscores as wide as the text they stand in for.
+fn blanks(text: &str) -> String {
+    "_".repeat(unicode_width::UnicodeWidthStr::width(text).max(1))
+}
+
+/// Where the next typed character goes, as a word index and a character index within that word.
+pub fn caret(input: &str) -> (usize, usize) {
+    let words = input.split_terminator(' ').count();
+
+    match input.split(' ').next_back() {
+        Some(word) if !word.is_empty() => (words - 1, graphemes(word).count()),
+        _ => (words, 0),
+    }
+}
+
+/// Colors the target text by how well the input matches it.
+/// Words with a glyph in `glyphs` are drawn as that glyph, colored by the state of the whole word.
+/// When `blind`, everything typed is drawn as if it were right, so mistakes don't show.
+pub fn color_text<'a>(
+    target: &str,
+    input: &str,
+    glyphs: &[Option<char>],
+    blind: bool,
+) -> ratatui::prelude::Text<'a> {
+    ratatui::text::Line::from(color_words(target, input, glyphs, blind, 0..usize::MAX)).into()
+}
+
+/// The colored words of `color_text` with an index in `words`.
+fn color_words(
+    target: &str,
+    input: &str,
+    glyphs: &[Option<char>],
+    blind: bool,
+    words: std::ops::Range<usize>,
+) -> Vec<ratatui::text::Span<'static>> {
+    let theme = crate::theme::current();
+
+    let default = ratatui::style::Style::new();
+    let blank = default.fg(theme.blank);
+    let correct = default.fg(theme.correct);
+    let (error, excess) = match blind {
+        true => (correct, correct),
+        false => crate::theme::MARKS.read().unwrap().styles(&theme),
+    };
+
+    let (caret_word, caret_char) = caret(input);
+    let caret =
+        |style: ratatui::style::Style| crate::config::CONFIG.caret.apply(style, theme.caret);
+
+    let mut colored_out = Vec::new();
+
+    full_zip(target.split_terminator(' '), input.split_terminator(' '))
+        .enumerate()
+        .skip(words.start)
+        .take(words.len())
+        .for_each(|(index, (target, input))| {
+            let at_caret = |char_index: usize| index == caret_word && char_index == caret_char;
+            // A word ended early with a space is missing the rest of its letters, while the word
+            // being typed just hasn't got to them yet.
+            let missing = match index < caret_word {
+                true => error,
+                false => blank,
+            };
+            let mut chars = 0;
+            let mut word = Vec::new();
+
+            match (target, input) {
+                (Some(target), input) if glyphs.get(index).copied().flatten().is_some() => {
+                    let glyph = glyphs[index].unwrap();
+                    let mut style = match input {
+                        None => blank,
+                        Some(input) if input == target => correct,
+                        Some(input) if target.starts_with(input) => missing,
+                        Some(input) if input.starts_with(target) => excess,
+                        Some(_) => error,
+                    };
+                    if index == caret_word {
+                        style = caret(style);
+                    }
+                    chars = usize::MAX;
+
+                    // Glyph fonts usually draw sitelen pona two cells wide, so leave room for it.
+                    word.push(ratatui::text::Span::raw(format!("{glyph} ")).style(style));
+                }
+                (Some(target), None) => {
+                    graphemes(target)
+                        .enumerate()
+                        .for_each(|(char_index, target)| {
+                            chars += 1;
+
+                            let style = match at_caret(char_index) {
+                                true => caret(blank),
+                                false => blank,
+                            };
+                            push(&mut word, blanks(target), style);
+                        });
+                }
+                (Some(target), Some(input)) => {
+                    full_zip(graphemes(target), graphemes(input))
+                        .enumerate()
+                        .for_each(|(char_index, (target, input))| {
+                            chars += 1;
+
+                            let (text, style) = match (target, input) {
+                                (Some(target), Some(input)) if target == input => {
+                                    (target.to_string(), correct)
+                                }
+                                (Some(target), Some(_)) => (target.to_string(), error),
+                                (Some(target), None) => (blanks(target), missing),
+                                (None, Some(input)) => (input.to_string(), excess),
+                                (None, None) => return,
+                            };
+
+                            let style = match at_caret(char_index) {
+                                true => caret(style),
+                                false => style,
+                            };
+                            push(&mut word, text, style);
+                        });
+                }
+                // Words typed past the end of the text are all extra letters.
+                (None, Some(input)) => {
+                    graphemes(input)
+                        .enumerate()
+                        .for_each(|(char_index, input)| {
+                            chars += 1;
+
+                            let style = match at_caret(char_index) {
+                                true => caret(excess),
+                                false => excess,
+                            };
+                            push(&mut word, input.to_string(), style);
+                        });
+                }
+                (None, None) => (),
+            }
+
+            // The caret sits on the separating space once the word has been typed to its end.
+            let style = match at_caret(chars) {
+                true => caret(blank),
+                false => blank,
+            };
+            colored_out.append(&mut word);
+            colored_out.push(ratatui::text::Span::raw(" ").style(style));
+        });
+
+    colored_out
+}
+
+/// Adds text to the last span of a word when it is styled the same, so a run of letters colored
+/// alike is drawn as one span instead of one per letter.
+fn push(word: &mut Vec<ratatui::text::Span<'static>>, text: String, style: ratatui::style::Style) {
+    match word.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(&text),
+        _ => word.push(ratatui::text::Span::styled(text, style)),
+    }
+}
+
+/// The colored words before the caret, kept between frames since a keystroke only changes the
+/// word being typed.
+#[derive(Default)]
+pub struct Colored {
+    /// The input the words were colored for, up to the space after the last of them.
+    input: String,
+    spans: Vec<ratatui::text::Span<'static>>,
+}
+
+impl Colored {
+    /// Colors the text like `color_text`, only coloring the words finished since the last call
+    /// and the ones after them.
+    pub fn color_text(
+        &mut self,
+        target: &str,
+        input: &str,
+        glyphs: &[Option<char>],
+        blind: bool,
+    ) -> ratatui::prelude::Text<'_> {
+        let typed = &input[..input.rfind(' ').map_or(0, |end| end + 1)];
+        if !typed.starts_with(&self.input) {
+            self.input.clear();
+            self.spans.clear();
+        }
+
+        let (done, words) = (self.input.matches(' ').count(), typed.matches(' ').count());
+        self.spans
+            .extend(color_words(target, input, glyphs, blind, done..words));
+        if words != done {
+            self.input = typed.to_string();
+        }
+
+        let spans: Vec<_> = self
+            .spans
+            .iter()
+            .map(|span| ratatui::text::Span::styled(span.content.as_ref(), span.style))
+            .chain(color_words(target, input, glyphs, blind, words..usize::MAX))
+            .collect();
+
+        ratatui::text::Line::from(spans).into()
+    }
+}
+
+/// Wraps colored text at word boundaries and keeps only the line before the caret word, the line
+/// holding it and the line after it, so long tests scroll instead of filling the screen.
+pub fn viewport<'a>(
+    text: ratatui::prelude::Text<'a>,
+    width: u16,
+    caret_word: usize,
+) -> ratatui::prelude::Text<'a> {
+    let (lines, caret_line) = wrap_lines(text, width, caret_word);
+
+    let first = caret_line.saturating_sub(1);
+    lines.into_iter().skip(first).take(3).collect()
+}
+
+/// Wraps colored text at word boundaries, keeping all of it.
+pub fn wrap<'a>(text: ratatui::prelude::Text<'a>, width: u16) -> ratatui::prelude::Text<'a> {
+    wrap_lines(text, width, 0).0.into_iter().collect()
+}
+
+/// The lines colored text wraps to and which of them holds the caret word.
+fn wrap_lines<'a>(
+    text: ratatui::prelude::Text<'a>,
+    width: u16,
+    caret_word: usize,
+) -> (Vec<ratatui::text::Line<'a>>, usize) {
+    let width = width as usize;
+
+    let mut lines: Vec<ratatui::text::Line<'a>> = vec![ratatui::text::Line::default()];
+    let mut word: Vec<ratatui::text::Span<'a>> = Vec::new();
+    let mut word_index = 0;
+    let mut caret_line = 0;
+
+    for span in text.lines.into_iter().flat_map(|line| line.spans) {
+        let separator = span.content == " ";
+        word.push(span);
+        if !separator {
+            continue;
+        }
+
+        let word_width: usize = word.iter().map(ratatui::text::Span::width).sum();
+        let line = lines.last_mut().unwrap();
+        if line.width() > 0 && line.width() + word_width > width {
+            lines.push(ratatui::text::Line::default());
+        }
+        if word_index == caret_word {
+            caret_line = lines.len() - 1;
+        }
+        lines.last_mut().unwrap().spans.append(&mut word);
+        word_index += 1;
+    }
+
+    (lines, caret_line)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    /// Text to type the way a test builds it, every word followed by a space.
+    fn target() -> impl Strategy<Value = String> {
+        proptest::collection::vec("[a-z]{1,6}", 0..8).prop_map(|words| {
+            words
+                .iter()
+                .map(|word| format!("{word} "))
+                .collect::<String>()
+        })
+    }
+
+    /// Typed text, with words left empty by runs of spaces and maybe a space at the end.
+    fn input() -> impl Strategy<Value = String> {
+        (
+            proptest::collection::vec("[a-z]{0,7}", 0..10),
+            any::<bool>(),
+        )
+            .prop_map(|(words, trailing)| match trailing {
+                true => words.join(" ") + " ",
+                false => words.join(" "),
+            })
+    }
+
+    /// The text of each word of colored text, told apart by the separating spaces.
+    fn words(spans: &[ratatui::text::Span]) -> Vec<String> {
+        let mut words = vec![String::new()];
+        spans.iter().for_each(|span| match span.content.as_ref() {
+            " " => words.push(String::new()),
+            content => words.last_mut().unwrap().push_str(content),
+        });
+        words.pop();
+
+        words
+    }
+
+    proptest! {
+        #[test]
+        fn full_zip_lasts_as_long_as_the_longer(
+            a in proptest::collection::vec(any::<u8>(), 0..20),
+            b in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let zipped: Vec<_> = super::full_zip(a.iter(), b.iter()).collect();
+
+            prop_assert_eq!(zipped.len(), a.len().max(b.len()));
+            prop_assert!(zipped.iter().all(|pair| pair != &(None, None)));
+            let (left, right): (Vec<_>, Vec<_>) = zipped.into_iter().unzip();
+            let left_items: Vec<_> = left.iter().flatten().copied().collect();
+            let right_items: Vec<_> = right.iter().flatten().copied().collect();
+            prop_assert_eq!(left_items, a.iter().collect::<Vec<_>>());
+            prop_assert_eq!(right_items, b.iter().collect::<Vec<_>>());
+            // Once an iterator runs out it stays out.
+            prop_assert!(left.iter().skip_while(|item| item.is_some()).all(Option::is_none));
+            prop_assert!(right.iter().skip_while(|item| item.is_some()).all(Option::is_none));
+        }
+
+        #[test]
+        fn extend_pads_with_none(
+            items in proptest::collection::vec(any::<u8>(), 0..20),
+            extra in 0..10usize,
+        ) {
+            let extended: Vec<_> = super::extend(items.iter().copied())
+                .take(items.len() + extra)
+                .collect();
+
+            let some: Vec<_> = items.iter().copied().map(Some).collect();
+            prop_assert_eq!(&extended[..items.len()], some);
+            prop_assert!(extended[items.len()..].iter().all(Option::is_none));
+        }
+
+        #[test]
+        fn colored_words_line_up(target in target(), input in input(), blind in any::<bool>()) {
+            crate::simulate::headless();
+            let text = super::color_text(&target, &input, &[], blind);
+            let words = words(&text.lines[0].spans);
+
+            let targets: Vec<&str> = target.split_terminator(' ').collect();
+            let inputs: Vec<&str> = input.split_terminator(' ').collect();
+            prop_assert_eq!(words.len(), targets.len().max(inputs.len()));
+            for (index, word) in words.iter().enumerate() {
+                let typed = inputs.get(index).map_or(0, |input| input.len());
+                let length = targets.get(index).map_or(0, |target| target.len());
+                prop_assert_eq!(word.len(), typed.max(length), "word {} of {:?}", index, words);
+            }
+        }
+
+        #[test]
+        fn colored_cache_matches_coloring_everything(
+            target in target(),
+            keys in proptest::collection::vec(prop_oneof![
+                3 => "[a-z ]".prop_map(|key| key.chars().next()),
+                1 => Just(None),
+            ], 0..40),
+        ) {
+            crate::simulate::headless();
+            let mut colored = super::Colored::default();
+            let mut input = String::new();
+
+            for key in keys {
+                match key {
+                    Some(c) => input.push(c),
+                    None => {
+                        input.pop();
+                    }
+                }
+
+                let cached = colored.color_text(&target, &input, &[], false);
+                let fresh = super::color_text(&target, &input, &[], false);
+                prop_assert_eq!(&cached.lines[0].spans, &fresh.lines[0].spans, "input {:?}", input);
+            }
+        }
+
+        #[test]
+        fn finished_words_show_missing_letters(target in target(), input in input()) {
+            crate::simulate::headless();
+            let text = super::color_text(&target, &input, &[], false);
+            let blank = ratatui::style::Style::new().fg(crate::theme::current().blank);
+            let (caret_word, _) = super::caret(&input);
+
+            let mut index = 0;
+            for span in &text.lines[0].spans {
+                match span.content.as_ref() {
+                    " " => index += 1,
+                    // Blanks stand in for letters not typed.
+                    content if index < caret_word && content.contains('_') => {
+                        prop_assert_ne!(span.style, blank, "word {}", index)
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        #[test]
+        fn caret_is_where_the_next_key_goes(input in input()) {
+            let (word, char) = super::caret(&input);
+            let typed: Vec<&str> = input.split(' ').collect();
+
+            prop_assert_eq!(word, typed.len() - 1);
+            prop_assert_eq!(char, typed.last().unwrap().len());
+        }
+    }
+}
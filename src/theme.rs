@@ -0,0 +1,167 @@
+/// Colors used for the typing text.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy)]
+#[serde(default)]
+pub struct Theme {
+    pub correct: ratatui::style::Color,
+    pub error: ratatui::style::Color,
+    pub excess: ratatui::style::Color,
+    pub blank: ratatui::style::Color,
+    pub hint: ratatui::style::Color,
+    pub caret: ratatui::style::Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        BUILT_IN[0].1
+    }
+}
+
+const BUILT_IN: [(&str, Theme); 7] = {
+    use ratatui::style::Color;
+
+    [
+        (
+            "default",
+            Theme {
+                correct: Color::Reset,
+                error: Color::Red,
+                excess: Color::LightYellow,
+                blank: Color::Reset,
+                hint: Color::Reset,
+                caret: Color::Reset,
+            },
+        ),
+        (
+            "light",
+            Theme {
+                correct: Color::Black,
+                error: Color::Red,
+                excess: Color::Rgb(0xb5, 0x89, 0x00),
+                blank: Color::Gray,
+                hint: Color::DarkGray,
+                caret: Color::Black,
+            },
+        ),
+        (
+            "dark",
+            Theme {
+                correct: Color::White,
+                error: Color::LightRed,
+                excess: Color::Yellow,
+                blank: Color::DarkGray,
+                hint: Color::Gray,
+                caret: Color::White,
+            },
+        ),
+        (
+            "high-contrast",
+            Theme {
+                correct: Color::Rgb(0xff, 0xff, 0xff),
+                error: Color::Rgb(0xff, 0x00, 0x00),
+                excess: Color::Rgb(0xff, 0xff, 0x00),
+                blank: Color::Rgb(0x80, 0x80, 0x80),
+                hint: Color::Rgb(0xff, 0xff, 0xff),
+                caret: Color::Rgb(0x00, 0xff, 0xff),
+            },
+        ),
+        // Mistakes in orange and extra letters in blue, which stay apart without telling red from
+        // green. Colors from the Okabe-Ito palette.
+        (
+            "deuteranopia",
+            Theme {
+                correct: Color::Reset,
+                error: Color::Rgb(0xd5, 0x5e, 0x00),
+                excess: Color::Rgb(0x56, 0xb4, 0xe9),
+                blank: Color::Reset,
+                hint: Color::Reset,
+                caret: Color::Reset,
+            },
+        ),
+        // Red looks dark without red cones, so mistakes get a brighter orange.
+        (
+            "protanopia",
+            Theme {
+                correct: Color::Reset,
+                error: Color::Rgb(0xe6, 0x9f, 0x00),
+                excess: Color::Rgb(0x56, 0xb4, 0xe9),
+                blank: Color::Reset,
+                hint: Color::Reset,
+                caret: Color::Reset,
+            },
+        ),
+        (
+            "gruvbox",
+            Theme {
+                correct: Color::Rgb(0xeb, 0xdb, 0xb2),
+                error: Color::Rgb(0xfb, 0x49, 0x34),
+                excess: Color::Rgb(0xfa, 0xbd, 0x2f),
+                blank: Color::Rgb(0x92, 0x83, 0x74),
+                hint: Color::Rgb(0x83, 0xa5, 0x98),
+                caret: Color::Rgb(0xfe, 0x80, 0x19),
+            },
+        ),
+    ]
+};
+
+/// How mistakes stand out from the rest of the text.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Marks {
+    /// By color, with wrong letters underlined.
+    #[default]
+    Color,
+    /// By symbols as well as color: wrong letters struck through and extra letters in italics.
+    Symbols,
+}
+
+impl Marks {
+    pub const ALL: [Marks; 2] = [Marks::Color, Marks::Symbols];
+
+    /// The styles of wrong and of extra letters.
+    pub fn styles(&self, theme: &Theme) -> (ratatui::style::Style, ratatui::style::Style) {
+        use ratatui::style::Stylize;
+
+        let default = ratatui::style::Style::new();
+        match self {
+            Marks::Color => (
+                default.fg(theme.error).underlined(),
+                default.fg(theme.excess),
+            ),
+            Marks::Symbols => (
+                default.fg(theme.error).crossed_out(),
+                default.fg(theme.excess).italic().underlined(),
+            ),
+        }
+    }
+}
+
+/// How mistakes are marked right now, starting out as set in the config file.
+pub static MARKS: std::sync::LazyLock<std::sync::RwLock<Marks>> =
+    std::sync::LazyLock::new(|| std::sync::RwLock::new(crate::config::CONFIG.marks));
+
+/// Name of the theme in use, starting out as the one from the config file.
+pub static THEME: std::sync::LazyLock<std::sync::RwLock<String>> =
+    std::sync::LazyLock::new(|| std::sync::RwLock::new(crate::config::CONFIG.theme.clone()));
+
+/// Names of the built-in themes followed by the ones from the config file.
+pub fn names() -> Vec<String> {
+    BUILT_IN
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .chain(crate::config::CONFIG.themes.keys().cloned())
+        .collect()
+}
+
+pub fn get(name: &str) -> Option<Theme> {
+    crate::config::CONFIG.themes.get(name).copied().or_else(|| {
+        BUILT_IN
+            .iter()
+            .find(|(built_in, _)| *built_in == name)
+            .map(|(_, theme)| *theme)
+    })
+}
+
+/// The theme in use, or the default one if the name is unknown.
+pub fn current() -> Theme {
+    get(&THEME.read().unwrap()).unwrap_or_default()
+}
@@ -0,0 +1,505 @@
+/// What a key did to a test.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    /// The input changed.
+    Typed,
+    /// The key was wrong in stop mode and left out of the input.
+    Refused,
+    /// The key had no effect.
+    Ignored,
+    /// The last word was typed, or the time ran out.
+    Finished,
+}
+
+/// The typing of a test, from the keys typed to the time spent on every word, without anything
+/// about the terminal, the config or the saved stats. The time is read from [`crate::clock`].
+pub struct Typing {
+    /// The words to type, each followed by a space.
+    pub words: String,
+    pub input: String,
+    /// The word being typed, which is also the number of words finished.
+    pub index: usize,
+    /// Time spent on each word so far, the space after it included.
+    pub durations: Vec<std::time::Duration>,
+    /// When the caret last crossed a word boundary.
+    enter: std::time::Instant,
+    /// When the first key of the test was pressed.
+    pub start: Option<std::time::Instant>,
+    /// When the test was paused, if it is paused right now.
+    pub paused: Option<std::time::Instant>,
+    /// Characters typed into the input, the ones later deleted included.
+    pub keystrokes: usize,
+    /// Index of the word being typed for every key refused in stop mode.
+    pub refused: Vec<usize>,
+    /// Every change to the input since the first key, to replay the test.
+    pub strokes: Vec<(f64, crate::replay::Stroke)>,
+    /// Length of a timed test, which ends when it runs out instead of at the last word.
+    limit: Option<std::time::Duration>,
+    strictness: crate::words::Strictness,
+    space: crate::words::Space,
+    hardcore: bool,
+}
+
+impl Typing {
+    /// An empty test following the rules of the settings, which words are then pushed onto.
+    pub fn new(settings: &crate::words::WordReq) -> Self {
+        Self {
+            words: String::new(),
+            input: String::new(),
+            index: 0,
+            durations: Vec::new(),
+            enter: crate::clock::now(),
+            start: None,
+            paused: None,
+            keystrokes: 0,
+            refused: Vec::new(),
+            strokes: Vec::new(),
+            limit: match settings.mode {
+                crate::words::Mode::Time => Some(std::time::Duration::from_secs(settings.time)),
+                _ => None,
+            },
+            strictness: settings.strictness,
+            space: settings.space,
+            hardcore: settings.hardcore,
+        }
+    }
+
+    /// Carries on with a saved test, paused until [`Typing::resume`].
+    pub fn restore(settings: &crate::words::WordReq, session: &crate::session::Session) -> Self {
+        let now = crate::clock::now();
+
+        Self {
+            words: session.words.clone(),
+            input: session.input.clone(),
+            index: session.index,
+            durations: session.durations.clone(),
+            enter: now,
+            start: Some(now.checked_sub(session.elapsed).unwrap_or(now)),
+            paused: Some(now),
+            keystrokes: session.keystrokes,
+            refused: session.refused.clone(),
+            strokes: session.strokes.clone(),
+            ..Self::new(settings)
+        }
+    }
+
+    /// Adds a word to the end of the test.
+    pub fn push_word(&mut self, word: &str) {
+        self.words.push_str(word);
+        self.words.push(' ');
+    }
+
+    /// Number of words in the test.
+    pub fn word_count(&self) -> usize {
+        self.words.split_terminator(' ').count()
+    }
+
+    pub fn time_limit(&self) -> Option<std::time::Duration> {
+        self.limit
+    }
+
+    /// Types a character. A sitelen pona glyph from an UCSUR keyboard layout types its whole word
+    /// instead, see [`Typing::glyph`].
+    pub fn key(&mut self, c: char) -> Outcome {
+        if let Some(word) = crate::sitelen::word(c) {
+            return self.glyph(c, word);
+        }
+
+        if self.strictness == crate::words::Strictness::Stop && Some(c) != self.expected() {
+            return self.refuse();
+        }
+
+        if c == ' ' && self.space == crate::words::Space::Ignore && self.mid_word() {
+            return Outcome::Ignored;
+        }
+
+        self.begin();
+        self.keystrokes += 1;
+
+        let c = match c {
+            ' ' if self.space == crate::words::Space::Insert && self.mid_word() => {
+                crate::text::INSERTED_SPACE
+            }
+            c => c,
+        };
+        self.record(crate::replay::Stroke::Char(c));
+        match c {
+            ' ' => self.next_word(),
+            c => {
+                self.input.push(c);
+
+                // The last word needs no space after it once it is right.
+                if self.limit.is_none()
+                    && self.index + 1 == self.word_count()
+                    && self.words.split_terminator(' ').next_back()
+                        == self.input.split(' ').next_back()
+                {
+                    self.record(crate::replay::Stroke::Char(' '));
+                    self.next_word();
+                }
+            }
+        }
+
+        self.typed()
+    }
+
+    /// Types the word of a glyph along with the space after it, since glyphs are written without
+    /// spaces between them. The input keeps the word in Latin letters, so it is told apart from
+    /// the target like any typed word. In stop mode a glyph is refused unless its word is what is
+    /// left of the target.
+    fn glyph(&mut self, glyph: char, word: &str) -> Outcome {
+        let typed = self.input.rsplit(' ').next().unwrap_or_default();
+        let rest = self
+            .words
+            .split_terminator(' ')
+            .nth(self.index)
+            .and_then(|target| target.strip_prefix(typed));
+        if self.strictness == crate::words::Strictness::Stop && rest != Some(word) {
+            return self.refuse();
+        }
+
+        self.begin();
+        self.keystrokes += word.chars().count() + 1;
+        self.record(crate::replay::Stroke::Char(glyph));
+        self.input.push_str(word);
+        self.record(crate::replay::Stroke::Char(' '));
+        self.next_word();
+
+        self.typed()
+    }
+
+    /// Counts a key refused in stop mode as an error, not a keystroke. A refused key doesn't start
+    /// the test, and only counts once it has started.
+    fn refuse(&mut self) -> Outcome {
+        if !self.input.is_empty() {
+            self.refused.push(self.index);
+        }
+
+        Outcome::Refused
+    }
+
+    /// What a key that went into the input did, which is to finish the test after the last word.
+    fn typed(&self) -> Outcome {
+        match self.limit.is_none() && self.index >= self.word_count() {
+            true => Outcome::Finished,
+            false => Outcome::Typed,
+        }
+    }
+
+    /// Deletes the last character typed, going back into the previous word after a space.
+    pub fn backspace(&mut self) -> Outcome {
+        if self.hardcore {
+            return Outcome::Ignored;
+        }
+
+        self.begin();
+        self.record(crate::replay::Stroke::Backspace);
+        if let Some(' ') = self.input.pop() {
+            self.split();
+            self.index -= 1;
+        }
+
+        Outcome::Typed
+    }
+
+    /// Clears the word being typed, or the previous word when the current one is still empty.
+    /// Time spent so far stays with the word the caret ends up in.
+    pub fn delete_word(&mut self) -> Outcome {
+        if self.hardcore {
+            return Outcome::Ignored;
+        }
+
+        self.record(crate::replay::Stroke::DeleteWord);
+        if self.input.ends_with(' ') {
+            self.input.pop();
+            self.split();
+            self.index -= 1;
+        }
+
+        let start = self.input.rfind(' ').map_or(0, |space| space + 1);
+        self.input.truncate(start);
+
+        Outcome::Typed
+    }
+
+    /// Stops the clock from the given time, if anything has been typed to stop it for.
+    pub fn pause(&mut self, at: std::time::Instant) {
+        if !self.input.is_empty() && self.paused.is_none() {
+            self.paused = Some(at);
+        }
+    }
+
+    /// Starts the clock again, skipping the paused time by moving the timers past it.
+    pub fn resume(&mut self) {
+        if let Some(paused) = self.paused.take() {
+            let gap = crate::clock::elapsed(paused);
+            self.enter += gap;
+            if let Some(start) = &mut self.start {
+                *start += gap;
+            }
+        }
+    }
+
+    /// Whether a timed test has run out of time.
+    pub fn timed_out(&self) -> bool {
+        match (self.start, self.limit) {
+            (Some(start), Some(limit)) => self.now().duration_since(start) >= limit,
+            _ => false,
+        }
+    }
+
+    /// The current time as far as the test is concerned, which stands still while paused.
+    pub fn now(&self) -> std::time::Instant {
+        self.paused.unwrap_or_else(crate::clock::now)
+    }
+
+    /// Time since the first key, pauses left out.
+    pub fn since_start(&self) -> std::time::Duration {
+        self.start
+            .map(|start| self.now().duration_since(start))
+            .unwrap_or_default()
+    }
+
+    /// Time spent typing so far.
+    pub fn elapsed(&self) -> std::time::Duration {
+        if let (Some(start), Some(limit)) = (self.start, self.limit) {
+            return self.now().duration_since(start).min(limit);
+        }
+
+        let mut elapsed: std::time::Duration = self.durations.iter().sum();
+        if !self.input.is_empty() && !self.input.ends_with(' ') {
+            elapsed += self.now().duration_since(self.enter);
+        }
+
+        elapsed
+    }
+
+    /// The character the next key has to type, a space once the current word is done.
+    pub fn expected(&self) -> Option<char> {
+        let typed = self.input.rsplit(' ').next().unwrap_or_default();
+        let target = self.words.split_terminator(' ').nth(self.index)?;
+
+        match target.strip_prefix(typed) {
+            Some("") => Some(' '),
+            Some(rest) => rest.chars().next(),
+            None => None,
+        }
+    }
+
+    /// Whether the word being typed is still shorter than its target.
+    fn mid_word(&self) -> bool {
+        let typed = self.input.rsplit(' ').next().unwrap_or_default();
+
+        self.words
+            .split_terminator(' ')
+            .nth(self.index)
+            .is_some_and(|target| {
+                crate::text::graphemes(typed).count() < crate::text::graphemes(target).count()
+            })
+    }
+
+    /// Whether a word was left wrong or, in stop mode, had a key refused.
+    pub fn missed(&self, index: usize, target: &str, input: &str) -> bool {
+        target != input || self.refused.contains(&index)
+    }
+
+    /// Whether the word the last key went into has a mistake in it, or right after a space,
+    /// whether the word it finished does.
+    #[cfg(feature = "sound")]
+    pub fn mistyped(&self) -> bool {
+        let finished = self.input.ends_with(' ');
+        let mut typed = self.input.split(' ').enumerate().rev();
+        let (index, word) = match finished {
+            true => typed.nth(1),
+            false => typed.next(),
+        }
+        .unwrap_or_default();
+        let target = self
+            .words
+            .split_terminator(' ')
+            .nth(index)
+            .unwrap_or_default();
+
+        match finished {
+            true => word != target,
+            false => !target.starts_with(word),
+        }
+    }
+
+    /// The scores of what has been typed so far.
+    pub fn result(&self) -> crate::results::TestResult {
+        let mut result = crate::results::TestResult::new(&self.words, &self.input, self.elapsed());
+        result.count_keystrokes(self.keystrokes);
+        result.count_refused(self.refused.len());
+        result.set_timeline(
+            self.words
+                .split_terminator(' ')
+                .zip(self.input.split_terminator(' '))
+                .zip(&self.durations)
+                .take(self.index)
+                .enumerate()
+                .map(|(index, ((target, input), duration))| {
+                    // The space after a word is part of typing it.
+                    let minutes = duration.as_secs_f64() / 60.0;
+                    let wpm = match minutes > 0.0 {
+                        true => (input.chars().count() + 1) as f64 / 5.0 / minutes,
+                        false => 0.0,
+                    };
+
+                    (wpm, self.missed(index, target, input))
+                })
+                .collect(),
+        );
+
+        result
+    }
+
+    /// Starts the clock and the counts over on the first key of the test.
+    fn begin(&mut self) {
+        if self.input.is_empty() {
+            self.enter = crate::clock::now();
+            self.start = Some(self.enter);
+            self.durations.clear();
+            self.keystrokes = 0;
+            self.refused.clear();
+            self.strokes.clear();
+        }
+    }
+
+    /// Finishes the word being typed with a space.
+    fn next_word(&mut self) {
+        self.split();
+        self.input.push(' ');
+        self.index += 1;
+    }
+
+    /// Adds the time since the last word boundary to the current word.
+    fn split(&mut self) {
+        match self.durations.get_mut(self.index) {
+            Some(duration) => *duration += crate::clock::elapsed(self.enter),
+            None => self.durations.push(crate::clock::elapsed(self.enter)),
+        }
+        self.enter = crate::clock::now();
+    }
+
+    /// Logs a change to the input at the current time into the test.
+    fn record(&mut self, stroke: crate::replay::Stroke) {
+        let at = self.since_start();
+        self.strokes.push((at.as_secs_f64(), stroke));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Outcome;
+
+    fn typing(settings: crate::words::WordReq, words: &[&str]) -> super::Typing {
+        crate::clock::simulate();
+        let mut typing = super::Typing::new(&settings);
+        words.iter().for_each(|word| typing.push_word(word));
+
+        typing
+    }
+
+    fn type_text(typing: &mut super::Typing, text: &str) -> Vec<Outcome> {
+        text.chars()
+            .map(|c| {
+                crate::clock::advance(std::time::Duration::from_millis(100));
+                typing.key(c)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finishes_on_the_last_word() {
+        let mut typing = typing(Default::default(), &["toki", "pona"]);
+
+        let outcomes = type_text(&mut typing, "toki pona");
+        assert_eq!(outcomes.last(), Some(&Outcome::Finished));
+        assert_eq!(typing.input, "toki pona ");
+        assert_eq!(typing.index, 2);
+        // The clock starts at the first key.
+        assert_eq!(typing.elapsed(), std::time::Duration::from_millis(800));
+    }
+
+    #[test]
+    fn backspace_goes_back_a_word() {
+        let mut typing = typing(Default::default(), &["toki", "pona"]);
+
+        type_text(&mut typing, "toki ");
+        assert_eq!(typing.backspace(), Outcome::Typed);
+        assert_eq!(typing.input, "toki");
+        assert_eq!(typing.index, 0);
+        // The backspace took a character out rather than typing one.
+        assert_eq!(typing.keystrokes, 5);
+    }
+
+    #[test]
+    fn stop_mode_refuses_wrong_keys() {
+        let settings = crate::words::WordReq {
+            strictness: crate::words::Strictness::Stop,
+            ..Default::default()
+        };
+        let mut typing = typing(settings, &["toki"]);
+
+        assert_eq!(
+            type_text(&mut typing, "tx"),
+            [Outcome::Typed, Outcome::Refused]
+        );
+        assert_eq!(typing.input, "t");
+        assert_eq!(typing.keystrokes, 1);
+        assert_eq!(typing.refused, [0]);
+        assert!(typing.missed(0, "toki", "toki"));
+    }
+
+    #[test]
+    fn pauses_leave_time_out() {
+        let mut typing = typing(Default::default(), &["toki", "pona"]);
+
+        type_text(&mut typing, "to");
+        typing.pause(crate::clock::now());
+        crate::clock::advance(std::time::Duration::from_secs(30));
+        typing.resume();
+        type_text(&mut typing, "ki");
+        assert_eq!(typing.elapsed(), std::time::Duration::from_millis(300));
+    }
+
+    #[test]
+    fn glyphs_type_their_whole_word() {
+        let mut typing = typing(Default::default(), &["toki", "pona", "mute"]);
+
+        // toki, then a wrong glyph (ike) where pona goes, then mute.
+        assert_eq!(
+            type_text(&mut typing, "\u{F196C}\u{F190D}\u{F193C}"),
+            [Outcome::Typed, Outcome::Typed, Outcome::Finished]
+        );
+        assert_eq!(typing.input, "toki ike mute ");
+        // Each glyph typed its word and a space.
+        assert_eq!(typing.keystrokes, 14);
+        assert!(!typing.missed(0, "toki", "toki"));
+        assert!(typing.missed(1, "pona", "ike"));
+
+        let mut replayed = String::new();
+        typing
+            .strokes
+            .iter()
+            .for_each(|(_, stroke)| stroke.apply(&mut replayed));
+        assert_eq!(replayed, typing.input);
+    }
+
+    #[test]
+    fn stop_mode_refuses_wrong_glyphs() {
+        let settings = crate::words::WordReq {
+            strictness: crate::words::Strictness::Stop,
+            ..Default::default()
+        };
+        let mut typing = typing(settings, &["toki", "pona"]);
+
+        assert_eq!(
+            type_text(&mut typing, "\u{F196C}\u{F190D}\u{F1954}"),
+            [Outcome::Typed, Outcome::Refused, Outcome::Finished]
+        );
+        assert_eq!(typing.input, "toki pona ");
+        assert_eq!(typing.refused, [1]);
+    }
+}
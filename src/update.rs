@@ -0,0 +1,98 @@
+const LINKU_URL: &str = "https://api.linku.la/v1/words?lang=*";
+
+#[derive(serde::Deserialize)]
+struct LinkuTranslation {
+    definition: String,
+    commentary: String,
+}
+
+/// The parts of a Linku word entry that sona keeps.
+#[derive(serde::Deserialize)]
+struct LinkuWord {
+    id: String,
+    word: String,
+    usage_category: String,
+    deprecated: bool,
+    ku_data: Option<std::collections::HashMap<String, u16>>,
+    pu_verbatim: Option<std::collections::HashMap<String, String>>,
+    translations: std::collections::HashMap<String, LinkuTranslation>,
+    #[serde(default)]
+    audio: Vec<crate::words::Audio>,
+}
+
+impl LinkuWord {
+    /// Converts to the internal format, skipping usage categories sona does not know about.
+    fn into_word_data(self) -> Option<crate::words::WordData> {
+        let usage_category =
+            <crate::words::UsageCategory as clap::ValueEnum>::from_str(&self.usage_category, true)
+                .ok()?;
+        let translation = self.translations.get("en");
+        let translations = self
+            .translations
+            .iter()
+            .filter(|(lang, _)| *lang != "en")
+            .map(|(lang, translation)| (lang.clone(), translation.definition.clone()))
+            .collect();
+
+        Some(crate::words::WordData {
+            id: self.id,
+            usage_category,
+            word: self.word,
+            deprecated: self.deprecated,
+            ku_data: self.ku_data,
+            pu_verbatim: self.pu_verbatim,
+            commentary: translation.map(|translation| translation.commentary.clone()),
+            definitions: translation.map(|translation| translation.definition.clone()),
+            translations,
+            audio: self.audio,
+            pack: None,
+        })
+    }
+}
+
+/// Downloads a word recording to `path`, unless it is there already. It is written next to it
+/// first and only moved there once whole, so a download cut off partway is tried again.
+#[cfg(feature = "sound")]
+pub fn download(link: &str, path: &std::path::Path) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    let response = ureq::get(link)
+        .call()
+        .map_err(|err| format!("could not download {link}: {err}"))?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+    let partial = path.with_extension("part");
+    let mut file = std::fs::File::create(&partial).map_err(|err| err.to_string())?;
+    std::io::copy(&mut response.into_reader(), &mut file).map_err(|err| err.to_string())?;
+    std::fs::rename(&partial, path).map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+/// Downloads the current Linku word data and caches it in the data directory.
+/// Returns where the words were saved and how many there are.
+pub fn run() -> Result<(std::path::PathBuf, usize), String> {
+    let response = ureq::get(LINKU_URL)
+        .call()
+        .map_err(|err| format!("could not reach Linku: {err}"))?;
+
+    let linku: std::collections::HashMap<String, LinkuWord> =
+        serde_json::from_reader(response.into_reader())
+            .map_err(|err| format!("could not read the Linku word data: {err}"))?;
+
+    let mut words: Vec<crate::words::WordData> = linku
+        .into_values()
+        .filter_map(LinkuWord::into_word_data)
+        .collect();
+    words.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+
+    let count = words.len();
+    let path = crate::stats::data_file("linku.toml").ok_or("no data directory to save to")?;
+    crate::words::write_wordlist(&path, words)?;
+
+    Ok((path, count))
+}
@@ -0,0 +1,1146 @@
+#[allow(non_camel_case_types)]
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    clap::ValueEnum,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    bincode::Encode,
+    bincode::Decode,
+)]
+pub enum UsageCategory {
+    core,
+    common,
+    uncommon,
+    obscure,
+    sandbox,
+}
+
+/// A recording of someone saying a word, as listed by Linku.
+#[derive(serde::Deserialize, serde::Serialize, bincode::Encode, bincode::Decode, Debug, Clone)]
+pub struct Audio {
+    pub author: String,
+    pub link: String,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, bincode::Encode, bincode::Decode, Debug, Clone)]
+pub struct WordData {
+    pub id: String,
+    pub usage_category: UsageCategory,
+    pub word: String,
+    pub deprecated: bool,
+    pub ku_data: Option<std::collections::HashMap<String, u16>>,
+    pub pu_verbatim: Option<std::collections::HashMap<String, String>>,
+    pub commentary: Option<String>,
+    pub definitions: Option<String>,
+    /// Definitions in languages other than English, keyed by locale.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub translations: std::collections::HashMap<String, String>,
+    /// Recordings of the word, which only word data fetched with `sona update` has.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio: Vec<Audio>,
+    /// The word pack the word came from, or None for the built-in or downloaded words.
+    #[serde(skip)]
+    pub pack: Option<String>,
+}
+
+impl WordData {
+    /// The definition in the given language, falling back to English.
+    pub fn definition(&self, lang: &str) -> Option<&str> {
+        self.translations
+            .get(lang)
+            .or(self.definitions.as_ref())
+            .map(String::as_str)
+    }
+}
+
+/// Every language with definitions in WORDS, English first.
+pub fn languages() -> Vec<String> {
+    let mut languages: Vec<String> = WORDS
+        .iter()
+        .flat_map(|word| word.translations.keys())
+        .filter(|lang| *lang != "en")
+        .cloned()
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    languages.insert(0, "en".to_string());
+
+    languages
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug)]
+struct Words {
+    words: Vec<WordData>,
+}
+
+/// The user supplied word packs merged together and whether they replace the built-in words.
+static WORDLIST: std::sync::OnceLock<(Vec<WordData>, bool)> = std::sync::OnceLock::new();
+
+/// A file format word lists can be written in, all with the layout of res/words.toml.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Toml,
+    Json,
+    /// Only there with the "yaml" feature.
+    Yaml,
+}
+
+impl Format {
+    /// The format a file is in by its extension, TOML when it isn't one of the others.
+    pub fn of(path: &std::path::Path) -> Self {
+        match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some("json") => Format::Json,
+            Some("yaml" | "yml") => Format::Yaml,
+            _ => Format::Toml,
+        }
+    }
+}
+
+/// Reads a word list in the format its extension says, listing every problem with it when there
+/// are any.
+pub fn read_wordlist(path: &std::path::Path) -> Result<Vec<WordData>, String> {
+    let text = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    validate(&text, Format::of(path)).map_err(|problems| {
+        problems
+            .iter()
+            .map(|problem| format!("{}: {problem}", path.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+/// Merges word packs in order by id, a word in a later pack taking the place of the one with the
+/// same id in an earlier pack. Each word is marked with the name of the pack it came from.
+pub fn overlay(packs: Vec<(String, Vec<WordData>)>) -> Vec<WordData> {
+    let mut words: Vec<WordData> = Vec::new();
+    for (name, pack) in packs {
+        for mut word in pack {
+            word.pack = Some(name.clone());
+            match words.iter_mut().find(|known| known.id == word.id) {
+                Some(known) => *known = word,
+                None => words.push(word),
+            }
+        }
+    }
+
+    words
+}
+
+/// Reads word packs in order, each named by its file name without the extension.
+pub fn read_packs<'a>(
+    paths: impl IntoIterator<Item = &'a std::path::PathBuf>,
+) -> Result<Vec<(String, Vec<WordData>)>, String> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            Ok((name, read_wordlist(path)?))
+        })
+        .collect()
+}
+
+/// Where in a word list a problem is.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Place {
+    Line(usize),
+    /// The number of an entry counting from 1, for formats read without knowing the lines.
+    Entry(usize),
+}
+
+/// Something wrong with a word list.
+#[derive(Debug)]
+pub struct Problem {
+    pub place: Place,
+    pub message: String,
+}
+
+impl std::fmt::Display for Place {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Place::Line(line) => write!(f, "line {line}"),
+            Place::Entry(entry) => write!(f, "entry {entry}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.place, self.message)
+    }
+}
+
+/// An entry of a word list before it is checked, with where it is in the text when that is known.
+struct Entry {
+    value: serde_json::Value,
+    span: Option<std::ops::Range<usize>>,
+}
+
+/// The entries of a word list, or the problem that kept it from being read at all.
+fn entries(text: &str, format: Format) -> Result<Vec<Entry>, Problem> {
+    let line = |offset: usize| text[..offset.min(text.len())].matches('\n').count() + 1;
+    let unspanned = |values: Vec<serde_json::Value>| {
+        values
+            .into_iter()
+            .map(|value| Entry { value, span: None })
+            .collect()
+    };
+
+    match format {
+        Format::Toml => {
+            #[derive(serde::Deserialize)]
+            struct Entries {
+                words: Vec<toml::Spanned<toml::Table>>,
+            }
+
+            toml::from_str::<Entries>(text)
+                .map(|entries| {
+                    entries
+                        .words
+                        .into_iter()
+                        .map(|entry| Entry {
+                            span: Some(entry.span()),
+                            value: serde_json::to_value(entry.into_inner()).unwrap(),
+                        })
+                        .collect()
+                })
+                .map_err(|err| Problem {
+                    place: Place::Line(err.span().map_or(1, |span| line(span.start))),
+                    message: err.message().trim().replace('\n', ", "),
+                })
+        }
+        Format::Json => {
+            #[derive(serde::Deserialize)]
+            struct Entries {
+                words: Vec<serde_json::Value>,
+            }
+
+            serde_json::from_str::<Entries>(text)
+                .map(|entries| unspanned(entries.words))
+                .map_err(|err| Problem {
+                    place: Place::Line(err.line()),
+                    message: err.to_string(),
+                })
+        }
+        #[cfg(feature = "yaml")]
+        Format::Yaml => {
+            #[derive(serde::Deserialize)]
+            struct Entries {
+                words: Vec<serde_json::Value>,
+            }
+
+            serde_yaml::from_str::<Entries>(text)
+                .map(|entries| unspanned(entries.words))
+                .map_err(|err| Problem {
+                    place: Place::Line(err.location().map_or(1, |location| location.line())),
+                    message: err.to_string(),
+                })
+        }
+        #[cfg(not(feature = "yaml"))]
+        Format::Yaml => Err(Problem {
+            place: Place::Line(1),
+            message: "reading YAML needs sona to be built with the yaml feature".to_string(),
+        }),
+    }
+}
+
+/// Reads a word list, going through every entry rather than stopping at the first mistake.
+/// Entries that don't fit the layout, unknown usage categories, ids used more than once and words
+/// without any definition are all problems.
+pub fn validate(text: &str, format: Format) -> Result<Vec<WordData>, Vec<Problem>> {
+    let line = |offset: usize| text[..offset.min(text.len())].matches('\n').count() + 1;
+    // Where a key of an entry is, or where the entry starts without a key or when it isn't there.
+    let place = |index: usize, entry: &Entry, key: Option<&str>| match &entry.span {
+        Some(span) => Place::Line(
+            key.and_then(|key| {
+                text[span.clone()]
+                    .lines()
+                    .position(|text| text.trim_start().starts_with(key))
+            })
+            .map_or(line(span.start), |offset| line(span.start) + offset),
+        ),
+        None => Place::Entry(index + 1),
+    };
+
+    let entries = entries(text, format).map_err(|problem| vec![problem])?;
+
+    let mut problems = Vec::new();
+    let mut words = Vec::new();
+    let mut seen = std::collections::HashMap::new();
+    for (index, entry) in entries.into_iter().enumerate() {
+        let name = match entry.value.get("id").and_then(serde_json::Value::as_str) {
+            Some(id) => format!("word {id:?}"),
+            None => "a word".to_string(),
+        };
+
+        let category = entry.value.get("usage_category");
+        if let Some(category) = category.and_then(serde_json::Value::as_str) {
+            if <UsageCategory as clap::ValueEnum>::from_str(category, false).is_err() {
+                problems.push(Problem {
+                    place: place(index, &entry, Some("usage_category")),
+                    message: format!(
+                        "{name} has the unknown usage_category {category:?}, which has to be one \
+                        of core, common, uncommon, obscure or sandbox"
+                    ),
+                });
+                continue;
+            }
+        }
+
+        let word: WordData = match serde_json::from_value(entry.value.clone()) {
+            Ok(word) => word,
+            Err(err) => {
+                problems.push(Problem {
+                    place: place(index, &entry, None),
+                    message: format!("{name}: {err}"),
+                });
+                continue;
+            }
+        };
+
+        match seen.get(&word.id) {
+            Some(first) => problems.push(Problem {
+                place: place(index, &entry, Some("id")),
+                message: format!("{name} was already listed at {first}"),
+            }),
+            None => {
+                seen.insert(word.id.clone(), place(index, &entry, None));
+            }
+        }
+        if word.definitions.as_deref().is_none_or(str::is_empty) && word.translations.is_empty() {
+            problems.push(Problem {
+                place: place(index, &entry, None),
+                message: format!("{name} has no definitions, so it can't come up in tests"),
+            });
+        }
+
+        words.push(word);
+    }
+
+    match problems.is_empty() {
+        true => Ok(words),
+        false => Err(problems),
+    }
+}
+
+pub fn write_wordlist(path: &std::path::Path, words: Vec<WordData>) -> Result<(), String> {
+    let toml = toml::to_string(&Words { words }).map_err(|err| err.to_string())?;
+
+    std::fs::write(path, toml).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Makes a word list part of WORDS, either merged over the built-in words by id or replacing them.
+/// Has to be called before WORDS is first used.
+pub fn use_wordlist(words: Vec<WordData>, replace: bool) {
+    let _ = WORDLIST.set((words, replace));
+}
+
+/// The words sona was built with, without downloaded word data or packs. The daily challenge and
+/// the word of the day are drawn from these so they are the same for everyone.
+pub static BUILT_IN: std::sync::LazyLock<Vec<WordData>> = std::sync::LazyLock::new(|| {
+    #[cfg(feature = "zstd")]
+    let words = {
+        let compressed = include_bytes!(concat!(env!("OUT_DIR"), "/words.toml.zst"));
+        parsed("words.bin", compressed, || {
+            let toml = zstd::decode_all(compressed.as_slice()).unwrap();
+            Some(
+                toml::from_str::<Words>(std::str::from_utf8(&toml).unwrap())
+                    .unwrap()
+                    .words,
+            )
+        })
+        .unwrap()
+    };
+
+    #[cfg(all(feature = "compressed", not(feature = "zstd")))]
+    let words = {
+        let compressed = include_bytes!(concat!(env!("OUT_DIR"), "/words.toml.bz2"));
+        parsed("words.bin", compressed, || {
+            let mut toml = String::new();
+            std::io::Read::read_to_string(
+                &mut bzip2::read::BzDecoder::new(compressed.as_slice()),
+                &mut toml,
+            )
+            .unwrap();
+            Some(toml::from_str::<Words>(&toml).unwrap().words)
+        })
+        .unwrap()
+    };
+
+    #[cfg(not(any(feature = "compressed", feature = "zstd")))]
+    let words = {
+        let toml = include_str!("../res/words.toml");
+        parsed("words.bin", toml.as_bytes(), || {
+            Some(toml::from_str::<Words>(toml).unwrap().words)
+        })
+        .unwrap()
+    };
+
+    words
+});
+
+pub static WORDS: std::sync::LazyLock<Vec<WordData>> = std::sync::LazyLock::new(|| {
+    if let Some((wordlist, true)) = WORDLIST.get() {
+        return wordlist.clone();
+    }
+
+    // Word data downloaded with `sona update` takes the place of the built-in words.
+    let mut words = crate::stats::data_file("linku.toml")
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|toml| {
+            parsed("linku.bin", toml.as_bytes(), || {
+                validate(&toml, Format::Toml).ok()
+            })
+        })
+        .unwrap_or_else(|| BUILT_IN.clone());
+
+    if let Some((wordlist, false)) = WORDLIST.get() {
+        words.retain(|word| wordlist.iter().all(|custom| custom.id != word.id));
+        words.extend(wordlist.iter().cloned());
+    }
+
+    words
+});
+
+/// Words saved with the editor since sona started, which take the place of the words in WORDS
+/// with the same id.
+static SAVED: std::sync::Mutex<Vec<&'static WordData>> = std::sync::Mutex::new(Vec::new());
+
+/// Every word, which is WORDS with the words saved since sona started laid over it.
+pub fn all() -> Vec<&'static WordData> {
+    let saved = SAVED.lock().unwrap();
+
+    WORDS
+        .iter()
+        .filter(|word| saved.iter().all(|known| known.id != word.id))
+        .chain(saved.iter().copied())
+        .collect()
+}
+
+/// The word pack words made in the editor are saved to, laid under the other packs on start.
+pub fn personal_pack() -> Option<std::path::PathBuf> {
+    crate::stats::data_file("nimi-sin.toml")
+}
+
+/// Saves a word to the personal pack in place of the word with the same id there, and makes it
+/// part of every word from now on.
+pub fn save_personal(mut word: WordData) -> Result<(), String> {
+    let path = personal_pack().ok_or("there is no data directory to save the word in")?;
+    let mut words = match path.exists() {
+        true => read_wordlist(&path)?,
+        false => Vec::new(),
+    };
+    words.retain(|known| known.id != word.id);
+    words.push(word.clone());
+    write_wordlist(&path, words)?;
+
+    word.pack = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned());
+    let mut saved = SAVED.lock().unwrap();
+    saved.retain(|known| known.id != word.id);
+    saved.push(Box::leak(Box::new(word)));
+
+    Ok(())
+}
+
+/// Parses word data, or reads it from the cache when the same data was parsed before, since
+/// decoding it is much faster than parsing TOML. The cache is told apart by a hash of the source
+/// and the version of sona, so it is made again whenever either changes. Each source has a cache
+/// file of its own.
+fn parsed(
+    cache: &str,
+    source: &[u8],
+    parse: impl FnOnce() -> Option<Vec<WordData>>,
+) -> Option<Vec<WordData>> {
+    let key = hash(&[source, env!("CARGO_PKG_VERSION").as_bytes()].concat());
+    let path = crate::stats::cache_file(cache);
+
+    let cached = path
+        .as_ref()
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|bytes| {
+            bincode::decode_from_slice::<(u64, Vec<WordData>), _>(
+                &bytes,
+                bincode::config::standard(),
+            )
+            .ok()
+        })
+        .and_then(|((cached, words), _)| (cached == key).then_some(words));
+    if cached.is_some() {
+        return cached;
+    }
+
+    let words = parse()?;
+    if let Some(path) = path {
+        let bytes = bincode::encode_to_vec((key, &words), bincode::config::standard()).unwrap();
+        let _ = std::fs::write(path, bytes);
+    }
+
+    Some(words)
+}
+
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Type a fixed number of words.
+    Words,
+    /// Type as many words as possible before the time runs out.
+    Time,
+    /// Type freely without any target text.
+    Zen,
+    /// Type each word from its definition alone.
+    Quiz,
+    /// Pick the right definition for each word out of four.
+    Choice,
+    /// Type the words of the day, which are the same for everyone.
+    Daily,
+    /// Type each word from hearing it said.
+    Dictation,
+    /// Type set phrases of several words, each counted as one.
+    Phrases,
+    /// Type generated sentences instead of unrelated words.
+    Sentences,
+    /// Type an excerpt of a toki pona text.
+    Quotes,
+    /// Type sentences with numbers, names and punctuation, which need shift and the punctuation
+    /// keys.
+    Numbers,
+    /// Type the words unlocked so far in the course, which grows as they are learned.
+    Learn,
+    /// Type words dense in the letters being drilled.
+    Drill,
+    /// Type the meaning of each word shown.
+    Definitions,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 14] = [
+        Mode::Words,
+        Mode::Time,
+        Mode::Zen,
+        Mode::Quiz,
+        Mode::Choice,
+        Mode::Daily,
+        Mode::Dictation,
+        Mode::Phrases,
+        Mode::Sentences,
+        Mode::Quotes,
+        Mode::Numbers,
+        Mode::Learn,
+        Mode::Drill,
+        Mode::Definitions,
+    ];
+
+    /// Whether the mode is about typing speed, so wpm is worth showing.
+    pub fn is_timed_typing(&self) -> bool {
+        !matches!(self, Mode::Zen | Mode::Choice)
+    }
+}
+
+/// Test lengths offered for timed tests, in seconds.
+pub const TIMES: [u64; 4] = [15, 30, 60, 120];
+
+/// When the definition of the word being typed is shown.
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    /// As soon as the word comes up.
+    Always,
+    /// Once nothing has been typed for a while.
+    Delayed,
+    /// Only when the hint key is pressed.
+    OnDemand,
+    /// Never.
+    Off,
+}
+
+impl Hint {
+    pub const ALL: [Hint; 4] = [Hint::Always, Hint::Delayed, Hint::OnDemand, Hint::Off];
+}
+
+/// What happens when a wrong key is pressed.
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Strictness {
+    /// The key is typed anyway and can be left wrong.
+    Forgiving,
+    /// The key is refused, so the caret waits until the right one is pressed.
+    Stop,
+}
+
+impl Strictness {
+    pub const ALL: [Strictness; 2] = [Strictness::Forgiving, Strictness::Stop];
+}
+
+/// Which way round quiz mode asks.
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Direction {
+    /// Shows a definition and asks for the toki pona word.
+    Recall,
+    /// Shows a toki pona word and asks for its meaning.
+    Recognize,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 2] = [Direction::Recall, Direction::Recognize];
+}
+
+/// What space does before the word being typed is complete.
+#[derive(
+    serde::Deserialize, serde::Serialize, clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Space {
+    /// Moves on to the next word, leaving the rest of this one wrong.
+    Skip,
+    /// Goes into the word as a wrong character.
+    Insert,
+    /// Does nothing.
+    Ignore,
+}
+
+impl Space {
+    pub const ALL: [Space; 3] = [Space::Skip, Space::Insert, Space::Ignore];
+}
+
+/// Sets of letters the letter filters step through on the settings screen: none, the vowels, the
+/// consonants, and the letters on each row of a QWERTY keyboard.
+pub const LETTER_SETS: [&str; 6] = ["", "aeiou", "jklmnpstw", "wetuiop", "asjkl", "nm"];
+
+/// Waits offered before a delayed hint, in seconds.
+pub const HINT_DELAYS: [u64; 5] = [1, 2, 3, 5, 10];
+
+#[derive(serde::Deserialize, serde::Serialize, Clone)]
+#[serde(default)]
+pub struct WordReq {
+    /// Language the definitions are shown in.
+    pub lang: String,
+    pub mode: Mode,
+    /// Length of a timed test in seconds.
+    pub time: u64,
+    pub in_use: bool,
+    pub deprecated: bool,
+    pub core: bool,
+    pub common: bool,
+    pub uncommon: bool,
+    pub obscure: bool,
+    pub sandbox: bool,
+    pub ku: bool,
+    pub pu: bool,
+    pub commentary: bool,
+    pub definitions: bool,
+    pub adaptive: bool,
+    pub review: bool,
+    /// Show words as sitelen pona where a glyph is known.
+    pub sitelen_pona: bool,
+    /// Show a keyboard under the text that marks the keys being pressed.
+    pub keyboard: bool,
+    /// Draw words again when the test is longer than the words available.
+    pub repeat: bool,
+    /// Show how far the personal best with the same settings had got at the same time.
+    pub ghost: bool,
+    /// Turn off backspace and deleting words, so every key typed stays in the score.
+    pub hardcore: bool,
+    /// Draw everything typed in one color, keeping mistakes hidden until the results.
+    pub blind: bool,
+    /// Show only the text and what it is typed from, without the status line and progress.
+    pub minimal: bool,
+    pub hint: Hint,
+    /// Seconds without typing before a delayed hint is shown.
+    pub hint_delay: u64,
+    /// Whether wrong keys are typed or refused.
+    pub strictness: Strictness,
+    /// What space does in the middle of a word.
+    pub space: Space,
+    /// Whether quiz mode asks for words or for their meanings.
+    pub direction: Direction,
+    /// How involved the sentences of sentence mode are.
+    pub complexity: crate::sentence::Complexity,
+    /// Letters drill mode picks words full of.
+    pub letters: String,
+    /// Fewest letters a word may have, with 0 for any.
+    pub min_length: usize,
+    /// Most letters a word may have, with 0 for any.
+    pub max_length: usize,
+    /// Letters a word must have at least one of, with none for any word.
+    pub with_letters: String,
+    /// Letters a word must not have any of.
+    pub without_letters: String,
+    /// Ids of the only words to use, when practicing the words missed in a test.
+    /// Every one of them goes into the test, whatever n is. An id listed several times doesn't
+    /// come up more often, but with repeat on it makes the test longer by drawing them all again.
+    #[serde(skip)]
+    pub only: Vec<String>,
+    /// Name of the lesson being taken, whose words the test is made of instead.
+    #[serde(skip)]
+    pub lesson: Option<String>,
+    /// Ids of the words of the test in order, as a challenge from a server gives them so everyone
+    /// types the same words whatever their own word data and stats are.
+    #[serde(skip)]
+    pub fixed: Vec<String>,
+    pub n: usize,
+    #[serde(
+        serialize_with = "serialize_seed",
+        deserialize_with = "deserialize_seed"
+    )]
+    pub seed: Option<u64>,
+}
+
+/// TOML integers stop at i64::MAX, so larger seeds are written as strings.
+fn serialize_seed<S: serde::Serializer>(
+    seed: &Option<u64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match seed {
+        Some(seed) => match i64::try_from(*seed) {
+            Ok(seed) => serializer.serialize_some(&seed),
+            Err(_) => serializer.serialize_some(&seed.to_string()),
+        },
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Reads a seed written as a number, or as text the way `--seed` reads it.
+fn deserialize_seed<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Seed {
+        Number(u64),
+        Text(String),
+    }
+
+    Ok(
+        match <Option<Seed> as serde::Deserialize>::deserialize(deserializer)? {
+            Some(Seed::Number(seed)) => Some(seed),
+            Some(Seed::Text(seed)) => parse_seed(&seed).ok(),
+            None => None,
+        },
+    )
+}
+
+impl Default for WordReq {
+    fn default() -> Self {
+        Self {
+            lang: "en".to_string(),
+            mode: Mode::Words,
+            time: 30,
+            in_use: true,
+            deprecated: false,
+            core: true,
+            common: false,
+            uncommon: false,
+            obscure: false,
+            sandbox: false,
+            ku: false,
+            pu: false,
+            commentary: false,
+            definitions: true,
+            adaptive: false,
+            review: false,
+            sitelen_pona: false,
+            keyboard: false,
+            repeat: false,
+            ghost: true,
+            hardcore: false,
+            blind: false,
+            minimal: false,
+            hint: Hint::Always,
+            hint_delay: 3,
+            strictness: Strictness::Forgiving,
+            space: Space::Skip,
+            direction: Direction::Recall,
+            complexity: crate::sentence::Complexity::Simple,
+            letters: "jwn".to_string(),
+            min_length: 0,
+            max_length: 0,
+            with_letters: String::new(),
+            without_letters: String::new(),
+            only: Vec::new(),
+            lesson: None,
+            fixed: Vec::new(),
+            n: 50,
+            seed: None,
+        }
+    }
+}
+
+impl WordReq {
+    /// Whether a word passes the filters on its length and letters.
+    pub fn fits(&self, word: &str) -> bool {
+        let length = word.chars().count();
+
+        length >= self.min_length
+            && (self.max_length == 0 || length <= self.max_length)
+            && (self.with_letters.is_empty() || word.chars().any(|c| self.with_letters.contains(c)))
+            && !word.chars().any(|c| self.without_letters.contains(c))
+    }
+
+    /// Every toggleable field paired with the label shown on the settings screen.
+    pub fn flags(&mut self) -> [(&'static str, &mut bool); 20] {
+        [
+            ("in use words", &mut self.in_use),
+            ("deprecated words", &mut self.deprecated),
+            ("core", &mut self.core),
+            ("common", &mut self.common),
+            ("uncommon", &mut self.uncommon),
+            ("obscure", &mut self.obscure),
+            ("sandbox", &mut self.sandbox),
+            ("require ku data", &mut self.ku),
+            ("require pu definitions", &mut self.pu),
+            ("require commentary", &mut self.commentary),
+            ("require definitions", &mut self.definitions),
+            ("adaptive selection", &mut self.adaptive),
+            ("repeat words to fill the test", &mut self.repeat),
+            ("only words due for review", &mut self.review),
+            ("show sitelen pona", &mut self.sitelen_pona),
+            ("show keyboard", &mut self.keyboard),
+            ("race your personal best", &mut self.ghost),
+            ("hardcore, no backspace", &mut self.hardcore),
+            ("blind, mistakes hidden until the end", &mut self.blind),
+            ("minimal, only the text", &mut self.minimal),
+        ]
+    }
+}
+
+/// The seed a test is built from: the one asked for, or a random one that can be shared later.
+pub fn seed(settings: &WordReq) -> u64 {
+    settings.seed.unwrap_or_else(rand::random)
+}
+
+/// The rng all of a test's randomness comes from, so a seed always gives the same test.
+pub fn seeded_rng(seed: u64) -> rand::rngs::StdRng {
+    rand::SeedableRng::seed_from_u64(seed)
+}
+
+/// Turns a seed typed by a person into a number. Numbers are used as they are and anything else
+/// is hashed.
+pub fn parse_seed(seed: &str) -> Result<u64, std::convert::Infallible> {
+    Ok(seed.parse().unwrap_or_else(|_| hash(seed.as_bytes())))
+}
+
+/// FNV-1a, which unlike the std hasher is the same on every build.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Every word the settings allow, in dictionary order. Words asked for by id in `only` are used
+/// whatever their category and even when banned, since they were picked one by one. The daily
+/// challenge only draws from the built-in words and leaves no word out for being banned, so it is
+/// the same for everyone.
+pub fn candidates<'a>(settings: &WordReq) -> Vec<&'a WordData> {
+    let schedule = crate::srs::SCHEDULE.lock().unwrap();
+    let banned = crate::banned::BANNED.lock().unwrap();
+    let any = !settings.only.is_empty();
+    let daily = settings.mode == Mode::Daily;
+
+    let words = match daily {
+        true => BUILT_IN.iter().collect(),
+        false => all(),
+    };
+    words
+        .into_iter()
+        .filter(|data| settings.in_use | data.deprecated)
+        .filter(|data| settings.deprecated | !data.deprecated)
+        .filter(|data| any | settings.core | (data.usage_category != UsageCategory::core))
+        .filter(|data| any | settings.common | (data.usage_category != UsageCategory::common))
+        .filter(|data| any | settings.uncommon | (data.usage_category != UsageCategory::uncommon))
+        .filter(|data| any | settings.obscure | (data.usage_category != UsageCategory::obscure))
+        .filter(|data| any | settings.sandbox | (data.usage_category != UsageCategory::sandbox))
+        .filter(|data| settings.mode != Mode::Dictation || !data.audio.is_empty())
+        .filter(|data| !settings.ku | data.ku_data.is_some())
+        .filter(|data| !settings.pu | data.pu_verbatim.is_some())
+        .filter(|data| !settings.commentary | data.commentary.is_some())
+        .filter(|data| !settings.definitions | data.definitions.is_some())
+        .filter(|data| !settings.review | schedule.is_due(&data.id))
+        .filter(|data| settings.only.is_empty() || settings.only.contains(&data.id))
+        .filter(|data| settings.fits(&data.word))
+        .filter(|data| any || daily || !banned.contains(&data.id))
+        .collect()
+}
+
+/// The words of `settings.fixed` in order, leaving out any this word data doesn't have.
+pub fn fixed(settings: &WordReq) -> Vec<&'static WordData> {
+    let words: std::collections::HashMap<&str, &'static WordData> = all()
+        .into_iter()
+        .map(|word| (word.id.as_str(), word))
+        .collect();
+
+    settings
+        .fixed
+        .iter()
+        .filter_map(|id| words.get(id.as_str()).copied())
+        .collect()
+}
+
+/// Samples `settings.n` of the allowed words with the given rng, in random order.
+/// With fewer words available the test is cut short, unless `settings.repeat` asks for the
+/// words to be drawn again until the test is full.
+pub fn subset<'a>(settings: &WordReq, rng: &mut impl rand::Rng) -> Vec<&'a WordData> {
+    use rand::seq::SliceRandom;
+
+    let words = candidates(settings);
+    let word_errors = crate::stats::WORD_ERRORS.lock().unwrap();
+    let n = match settings.only.is_empty() {
+        true => settings.n,
+        false => settings.only.len(),
+    };
+
+    let mut subset: Vec<&WordData> = Vec::new();
+    while subset.len() < n {
+        let wanted = n - subset.len();
+
+        let mut round: Vec<&WordData> = match settings.adaptive {
+            true => words
+                .choose_multiple_weighted(&mut *rng, wanted, |data| word_errors.weight(&data.id))
+                .unwrap()
+                .copied()
+                .collect(),
+            false => words.choose_multiple(&mut *rng, wanted).copied().collect(),
+        };
+        // choose_multiple picks the words fairly but doesn't promise a random order.
+        round.shuffle(rng);
+
+        if round.is_empty() {
+            break;
+        }
+        subset.append(&mut round);
+
+        if !settings.repeat {
+            break;
+        }
+    }
+
+    subset
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn built_in_words_are_valid() {
+        let words =
+            super::validate(include_str!("../res/words.toml"), super::Format::Toml).unwrap();
+        assert!(!words.is_empty());
+    }
+
+    #[test]
+    fn lists_every_problem_with_its_line() {
+        let toml = r#"
+[[words]]
+id = "toki"
+usage_category = "core"
+word = "toki"
+deprecated = false
+definitions = "speech"
+
+[[words]]
+id = "pona"
+usage_category = "kore"
+word = "pona"
+deprecated = false
+definitions = "good"
+
+[[words]]
+id = "toki"
+usage_category = "core"
+word = "toki"
+deprecated = false
+"#;
+        let problems = super::validate(toml, super::Format::Toml).err().unwrap();
+
+        let places: Vec<&super::Place> = problems.iter().map(|problem| &problem.place).collect();
+        assert_eq!(
+            places,
+            [
+                &super::Place::Line(11),
+                &super::Place::Line(17),
+                &super::Place::Line(16)
+            ]
+        );
+        assert!(problems[0].message.contains("\"kore\""));
+        assert!(problems[1].message.contains("already listed at line 2"));
+        assert!(problems[2].message.contains("no definitions"));
+    }
+
+    #[test]
+    fn reads_every_format_alike() {
+        let json = r#"{"words": [
+            {"id": "toki", "usage_category": "core", "word": "toki", "deprecated": false,
+             "definitions": "speech", "commentary": null},
+            {"id": "toki", "usage_category": "core", "word": "toki", "deprecated": false}
+        ]}"#;
+        let problems = super::validate(json, super::Format::Json).err().unwrap();
+        assert_eq!(problems[0].place, super::Place::Entry(2));
+        assert!(problems[0].message.contains("already listed at entry 1"));
+
+        let broken = super::validate("{\"words\": [\n{,", super::Format::Json).err();
+        assert_eq!(broken.unwrap()[0].place, super::Place::Line(2));
+
+        #[cfg(feature = "yaml")]
+        {
+            let yaml = "words:\n  - id: toki\n    usage_category: core\n    word: toki\n    \
+                        deprecated: false\n    definitions: speech\n";
+            let words = super::validate(yaml, super::Format::Yaml).unwrap();
+            assert_eq!(words[0].definitions.as_deref(), Some("speech"));
+        }
+    }
+
+    #[test]
+    fn later_packs_override_earlier_ones() {
+        let pack = |definitions: &[(&str, &str)]| {
+            let toml: String = definitions
+                .iter()
+                .map(|(id, definition)| {
+                    format!(
+                        "[[words]]\nid = \"{id}\"\nusage_category = \"core\"\nword = \"{id}\"\ndeprecated = false\ndefinitions = \"{definition}\"\n"
+                    )
+                })
+                .collect();
+            super::validate(&toml, super::Format::Toml).unwrap()
+        };
+
+        let words = super::overlay(vec![
+            (
+                "linku".to_string(),
+                pack(&[("toki", "speech"), ("pona", "good")]),
+            ),
+            (
+                "nimi-sin".to_string(),
+                pack(&[("kijetesantakalu", "raccoon")]),
+            ),
+            ("tomo-sona".to_string(), pack(&[("toki", "talk")])),
+        ]);
+
+        let found: Vec<_> = words
+            .iter()
+            .map(|word| {
+                (
+                    word.id.as_str(),
+                    word.definitions.as_deref(),
+                    word.pack.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("toki", Some("talk"), Some("tomo-sona")),
+                ("pona", Some("good"), Some("linku")),
+                ("kijetesantakalu", Some("raccoon"), Some("nimi-sin")),
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_words_by_length_and_letters() {
+        let settings = super::WordReq {
+            min_length: 3,
+            max_length: 4,
+            with_letters: "jkl".to_string(),
+            without_letters: "w".to_string(),
+            ..Default::default()
+        };
+
+        assert!(settings.fits("toki"));
+        assert!(settings.fits("jan"));
+        assert!(!settings.fits("li"));
+        assert!(!settings.fits("kulupu"));
+        assert!(!settings.fits("pona"));
+        assert!(!settings.fits("wile"));
+        assert!(super::WordReq::default().fits("kijetesantakalu"));
+    }
+
+    #[test]
+    fn fixed_words_keep_their_order() {
+        let settings = super::WordReq {
+            fixed: ["toki", "nope", "a", "toki"].map(String::from).to_vec(),
+            ..Default::default()
+        };
+        let ids: Vec<&str> = super::fixed(&settings)
+            .iter()
+            .map(|word| word.id.as_str())
+            .collect();
+
+        assert_eq!(ids, ["toki", "a", "toki"]);
+    }
+
+    /// Ids of the words the settings allow.
+    fn candidates(settings: &super::WordReq) -> Vec<String> {
+        super::candidates(settings)
+            .iter()
+            .map(|word| word.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn only_picks_words_of_any_category() {
+        let uncommon = super::BUILT_IN
+            .iter()
+            .find(|word| word.usage_category == super::UsageCategory::uncommon && !word.deprecated)
+            .unwrap();
+        let settings = super::WordReq {
+            only: vec!["toki".to_string(), uncommon.id.clone()],
+            ..Default::default()
+        };
+
+        let mut expected = settings.only.clone();
+        expected.sort();
+        let mut ids = candidates(&settings);
+        ids.sort();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn review_picks_due_words() {
+        crate::srs::SCHEDULE.lock().unwrap().cards.insert(
+            "pona".to_string(),
+            crate::srs::Card {
+                due: 0,
+                ..Default::default()
+            },
+        );
+        let settings = super::WordReq {
+            review: true,
+            ..Default::default()
+        };
+
+        assert_eq!(candidates(&settings), ["pona"]);
+    }
+
+    #[test]
+    fn categories_pick_their_words() {
+        let settings = super::WordReq {
+            core: false,
+            common: true,
+            ..Default::default()
+        };
+        let words = super::candidates(&settings);
+
+        assert!(!words.is_empty());
+        assert!(words
+            .iter()
+            .all(|word| word.usage_category == super::UsageCategory::common));
+    }
+}
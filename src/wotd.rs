@@ -0,0 +1,154 @@
+/// Times the word of the day is typed in its drill.
+const REPETITIONS: usize = 10;
+
+/// Today's word, the same for everyone on the same day in UTC: a core or common word of the
+/// built-in words picked with the day as the seed, out of the words sorted by id so the order they
+/// were loaded in doesn't matter.
+pub fn word() -> Option<&'static crate::words::WordData> {
+    use rand::seq::SliceRandom;
+
+    let mut words: Vec<&crate::words::WordData> = crate::words::BUILT_IN
+        .iter()
+        .filter(|word| !word.deprecated && word.definitions.is_some())
+        .filter(|word| {
+            matches!(
+                word.usage_category,
+                crate::words::UsageCategory::core | crate::words::UsageCategory::common
+            )
+        })
+        .collect();
+    words.sort_by(|a, b| a.id.cmp(&b.id));
+
+    // Spread consecutive days apart, like the daily challenge, but not onto the same seeds.
+    let seed = crate::stats::today().wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    words.choose(&mut crate::words::seeded_rng(seed)).copied()
+}
+
+/// Settings of a short test that types the word of the day over and over.
+/// Only how the test is shown is kept from the given settings.
+pub fn settings(
+    base: &crate::words::WordReq,
+    word: &crate::words::WordData,
+) -> crate::words::WordReq {
+    crate::words::WordReq {
+        lang: base.lang.clone(),
+        sitelen_pona: base.sitelen_pona,
+        keyboard: base.keyboard,
+        hint: base.hint,
+        hint_delay: base.hint_delay,
+        only: vec![word.id.clone(); REPETITIONS],
+        repeat: true,
+        n: REPETITIONS,
+        ..Default::default()
+    }
+}
+
+/// The last day the word of the day was shown on launch.
+#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[serde(default)]
+pub struct Seen {
+    pub day: Option<u64>,
+}
+
+impl Seen {
+    /// Whether the word of the day hasn't been shown on launch yet today, marking it shown.
+    pub fn first_today() -> bool {
+        let seen: Self = crate::storage::load("wotd.toml");
+
+        let today = crate::stats::today();
+        crate::storage::save("wotd.toml", &Self { day: Some(today) });
+
+        seen.day != Some(today)
+    }
+}
+
+/// Shows the word of the day with everything known about it, and drills it on enter.
+pub struct WordOfTheDay {
+    settings: crate::words::WordReq,
+    word: Option<&'static crate::words::WordData>,
+}
+
+impl WordOfTheDay {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self {
+            settings,
+            word: word(),
+        }
+    }
+}
+
+impl crate::flow::Store for WordOfTheDay {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        if action.is(&crate::config::CONFIG.keys.quit) || action.char() == Some('q') {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        match (key.code, self.word) {
+            (KeyCode::Enter, Some(word)) => Some(crate::flow::State::Game {
+                settings: settings(&self.settings, word),
+            }),
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        use ratatui::style::Stylize;
+
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let [title, body, help] = ratatui::layout::Layout::vertical([
+            ratatui::layout::Constraint::Length(2),
+            ratatui::layout::Constraint::Min(0),
+            ratatui::layout::Constraint::Length(1),
+        ])
+        .areas(block.inner(frame.area()));
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new("word of the day".bold()),
+            title,
+        );
+
+        match self.word {
+            Some(word) => crate::dict::render(frame, body, word, &self.settings.lang),
+            None => frame.render_widget(
+                ratatui::widgets::Paragraph::new("no core or common words with definitions"),
+                body,
+            ),
+        }
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(
+                format!(
+                    "enter: type it {REPETITIONS} times   q/{}: settings",
+                    crate::keys::describe(&crate::config::CONFIG.keys.quit)
+                )
+                .dark_gray(),
+            ),
+            help,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn drills_a_common_word() {
+        let word = crate::words::WORDS
+            .iter()
+            .find(|word| word.usage_category == crate::words::UsageCategory::common)
+            .unwrap();
+        let settings = super::settings(&crate::words::WordReq::default(), word);
+
+        let drill = crate::words::subset(&settings, &mut crate::words::seeded_rng(1));
+        assert_eq!(drill.len(), super::REPETITIONS);
+        assert!(drill.iter().all(|drilled| drilled.id == word.id));
+    }
+}
@@ -0,0 +1,108 @@
+/// Free typing with no target text, only tracking speed and keystrokes.
+pub struct Zen {
+    settings: crate::words::WordReq,
+    input: String,
+    keystrokes: usize,
+    start: Option<std::time::Instant>,
+}
+
+impl Zen {
+    pub fn new(settings: crate::words::WordReq) -> Self {
+        Self {
+            settings,
+            input: String::new(),
+            keystrokes: 0,
+            start: None,
+        }
+    }
+
+    fn finish(&self) -> crate::flow::State {
+        let elapsed = self.start.map(crate::clock::elapsed).unwrap_or_default();
+
+        crate::flow::State::Results {
+            settings: self.settings.clone(),
+            result: Box::new(crate::results::TestResult::free(
+                &self.input,
+                self.keystrokes,
+                elapsed,
+            )),
+        }
+    }
+}
+
+impl crate::flow::Store for Zen {
+    fn reduce(&mut self, action: &crate::flow::Action) -> Option<crate::flow::State> {
+        use ratatui::crossterm::event::KeyCode;
+
+        let crate::flow::Action::Key(key) = action else {
+            return None;
+        };
+
+        let keys = &crate::config::CONFIG.keys;
+        if action.is(&keys.quit) {
+            return Some(self.finish());
+        }
+        if action.is(&keys.restart) {
+            return Some(crate::flow::State::Game {
+                settings: self.settings.clone(),
+            });
+        }
+        if action.is(&keys.settings) {
+            return Some(crate::flow::State::Settings {
+                settings: self.settings.clone(),
+            });
+        }
+
+        self.start.get_or_insert_with(crate::clock::now);
+
+        if action.is(&keys.delete_word) {
+            let trimmed = self.input.trim_end().len();
+            self.input.truncate(trimmed);
+            let start = self
+                .input
+                .rfind(char::is_whitespace)
+                .map_or(0, |space| space + 1);
+            self.input.truncate(start);
+            self.keystrokes += 1;
+
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Char(c) => self.input.push(c),
+            KeyCode::Enter => self.input.push('\n'),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            _ => return None,
+        }
+        self.keystrokes += 1;
+
+        #[cfg(feature = "sound")]
+        crate::sound::play(crate::sound::Sound::Key);
+
+        None
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame) {
+        let block =
+            ratatui::widgets::Block::new().padding(ratatui::widgets::Padding::new(1, 1, 1, 0));
+        let area = block.inner(crate::config::CONFIG.layout.apply(frame.area()));
+
+        // Keep the end of the input in view by scrolling past the lines that no longer fit.
+        let width = area.width.max(1) as usize;
+        let lines: usize = self
+            .input
+            .split('\n')
+            .map(|line| line.chars().count() / width + 1)
+            .sum();
+        let scroll = lines.saturating_sub(area.height as usize) as u16;
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(self.input.as_str())
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .scroll((scroll, 0)),
+            area,
+        );
+    }
+}